@@ -0,0 +1,57 @@
+use argon2::password_hash::rand_core::OsRng;
+use argon2::password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
+use argon2::{Algorithm, Argon2, Params, Version};
+
+use crate::{Error, Operation};
+
+/// An Argon2id password hash in PHC string format, returned by
+/// `FieldWrapper::argon2_hash` on `String` fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Argon2Hash(String);
+
+impl Argon2Hash {
+    pub(crate) fn hash(password: &str) -> crate::Result<Self> {
+        let m_cost_kib: u32 = 65_536;
+        let t_cost: u32 = 3;
+        let p_cost: u32 = match std::thread::available_parallelism() {
+            Ok(nz) => {
+                let v = nz.get() as u32;
+                if v == 0 { 1 } else { std::cmp::min(v, 4) }
+            }
+            Err(_) => 1,
+        };
+
+        let params = Params::new(m_cost_kib, t_cost, p_cost, Some(32))
+            .map_err(|e| Error::hash(Operation::Hash, e.to_string()))?;
+        let argon = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
+
+        let salt = SaltString::generate(&mut OsRng);
+        let phc = argon
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| Error::hash(Operation::Hash, e.to_string()))?;
+
+        Ok(Self(phc.to_string()))
+    }
+
+    /// Returns the PHC string.
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Checks `candidate` against the hash.
+    ///
+    /// # Errors
+    /// Returns an error if the stored PHC string cannot be parsed.
+    pub fn verify(&self, candidate: &str) -> crate::Result<bool> {
+        let parsed = PasswordHash::new(&self.0)
+            .map_err(|e| Error::hash(Operation::Verify, e.to_string()))?;
+
+        let argon = Argon2::default();
+
+        match argon.verify_password(candidate.as_bytes(), &parsed) {
+            Ok(()) => Ok(true),
+            Err(_) => Ok(false),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Marker traits selecting which conversions a derived type exposes.
+//!
+//! `#[derive(Parser)]` implements all of them unless the type restricts the
+//! list with `#[parser(only(...))]` or `#[parser(exclude(...))]`; every
+//! conversion of `Parse` and `FieldWrapper` requires the matching marker.
+
+/// Enables `arc()`, selected by the `arc` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `arc()`",
+    note = "add `arc` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Arc {}
+
+/// Enables `boxed()`, selected by the `boxed` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `boxed()`",
+    note = "add `boxed` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Box {}
+
+/// Enables `mutex()`, selected by the `mutex` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `mutex()`",
+    note = "add `mutex` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Mutex {}
+
+/// Enables `tokio_mutex()`, selected by the `tokio_mutex` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `tokio_mutex()`",
+    note = "add `tokio_mutex` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait TokioMutex {}
+
+/// Enables `ref_cell()`, selected by the `ref_cell` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `ref_cell()`",
+    note = "add `ref_cell` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait RefCell {}
+
+/// Enables `unsafe_cell()`, selected by the `unsafe_cell` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `unsafe_cell()`",
+    note = "add `unsafe_cell` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait UnsafeCell {}
+
+/// Enables `once_cell()`, selected by the `once_cell` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `once_cell()`",
+    note = "add `once_cell` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait OnceCell {}
+
+/// Enables `vec()`, selected by the `vec` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `vec()`",
+    note = "add `vec` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Vec {}
+
+/// Enables `hashmap()`, selected by the `hashmap` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `hashmap()`",
+    note = "add `hashmap` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait HashMap {}
+
+/// Enables `tuple()`, selected by the `tuple` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `tuple()`",
+    note = "add `tuple` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Tuple {}
+
+/// Enables `json()`, selected by the `json` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `json()`",
+    note = "add `json` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Json {}
+
+/// Enables `toml()`, selected by the `toml` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `toml()`",
+    note = "add `toml` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Toml {}
+
+/// Enables `bin()`, selected by the `bin` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `bin()`",
+    note = "add `bin` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Bin {}
+
+/// Enables `yaml()`, selected by the `yaml` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `yaml()`",
+    note = "add `yaml` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Yaml {}
+
+/// Enables `msgpack()`, selected by the `msgpack` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `msgpack()`",
+    note = "add `msgpack` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait MsgPack {}
+
+/// Enables `cbor()`, selected by the `cbor` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `cbor()`",
+    note = "add `cbor` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Cbor {}
+
+/// Enables `ron()`, selected by the `ron` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `ron()`",
+    note = "add `ron` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Ron {}
+
+/// Enables `csv()`, selected by the `csv` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `csv()`",
+    note = "add `csv` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Csv {}
+
+/// Enables `xml()`, selected by the `xml` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `xml()`",
+    note = "add `xml` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Xml {}
+
+/// Enables `postcard()`, selected by the `postcard` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `postcard()`",
+    note = "add `postcard` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Postcard {}
+
+/// Enables `jsonl()`, selected by the `jsonl` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `jsonl()`",
+    note = "add `jsonl` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Jsonl {}
+
+/// Enables `query_string()`, selected by the `query_string` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `query_string()`",
+    note = "add `query_string` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait QueryString {}
+
+/// Enables `from_env()`, selected by the `env` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `from_env()`",
+    note = "add `env` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Env {}
+
+/// Enables `ini()`, selected by the `ini` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `ini()`",
+    note = "add `ini` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Ini {}
+
+/// Enables `bson()`, selected by the `bson` option.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` does not enable `bson()`",
+    note = "add `bson` to `#[parser(only(...))]` or remove it from `#[parser(exclude(...))]`"
+)]
+pub trait Bson {}
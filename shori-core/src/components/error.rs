@@ -0,0 +1,284 @@
+use std::fmt;
+
+/// Boxed source error carried by [`Error`].
+pub type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Result type returned by every fallible method of `shori_core`.
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+/// Data format or algorithm an [`Error`] happened in.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Format {
+    Json,
+    Toml,
+    Bincode,
+    Hex,
+    Yaml,
+    MsgPack,
+    Cbor,
+    Ron,
+    Csv,
+    Xml,
+    Postcard,
+    QueryString,
+    Env,
+    Ini,
+    Bson,
+    Argon2,
+}
+
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Format::Json => "JSON",
+            Format::Toml => "TOML",
+            Format::Bincode => "bincode",
+            Format::Hex => "hex",
+            Format::Yaml => "YAML",
+            Format::MsgPack => "MessagePack",
+            Format::Cbor => "CBOR",
+            Format::Ron => "RON",
+            Format::Csv => "CSV",
+            Format::Xml => "XML",
+            Format::Postcard => "postcard",
+            Format::QueryString => "query string",
+            Format::Env => "environment variables",
+            Format::Ini => "INI",
+            Format::Bson => "BSON",
+            Format::Argon2 => "Argon2",
+        })
+    }
+}
+
+/// Operation that failed, reported by [`Error`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    Encode,
+    Decode,
+    Hash,
+    Verify,
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Operation::Encode => "encode",
+            Operation::Decode => "decode",
+            Operation::Hash => "hash",
+            Operation::Verify => "verify",
+        })
+    }
+}
+
+/// Error returned by every fallible method of `shori_core`.
+///
+/// It is `Send + Sync`, so it can cross `.await` points and threads, and
+/// keeps the underlying error of the format crate as its `source()`.
+#[non_exhaustive]
+#[derive(Debug)]
+pub enum Error {
+    /// Encoding a value into, or decoding it from, a data format failed.
+    Codec {
+        format: Format,
+        operation: Operation,
+        /// Key of the field the error happened in, if it is known.
+        path: Option<String>,
+        source: BoxError,
+    },
+    /// Hashing a password or verifying it against a hash failed.
+    Hash {
+        format: Format,
+        operation: Operation,
+        source: BoxError,
+    },
+    /// Decoding a multi-record input failed; every failing record is
+    /// listed, in input order.
+    Records {
+        format: Format,
+        operation: Operation,
+        errors: Vec<RecordError>,
+    },
+}
+
+/// Failure of a single record of a multi-record input, listed by
+/// [`Error::Records`].
+#[derive(Debug)]
+pub struct RecordError {
+    line: Option<u64>,
+    key: Option<String>,
+    source: BoxError,
+}
+
+// Only the record-based formats build record errors.
+#[allow(dead_code)]
+impl RecordError {
+    pub(crate) fn new(line: Option<u64>, key: Option<&str>, source: impl Into<BoxError>) -> Self {
+        Self {
+            line,
+            key: key.map(str::to_string),
+            source: source.into(),
+        }
+    }
+
+    /// Returns the 1-based line of the input the record starts on, if it is known.
+    pub fn line(&self) -> Option<u64> {
+        self.line
+    }
+
+    /// Returns the key of the field (column, variable, ...) that failed, if
+    /// the error is specific to one.
+    pub fn key(&self) -> Option<&str> {
+        self.key.as_deref()
+    }
+}
+
+impl fmt::Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, &self.key) {
+            (Some(line), Some(key)) => write!(f, "line {line} at `{key}`: ")?,
+            (Some(line), None) => write!(f, "line {line}: ")?,
+            (None, Some(key)) => write!(f, "at `{key}`: ")?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.source)
+    }
+}
+
+impl std::error::Error for RecordError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(self.source.as_ref())
+    }
+}
+
+// The constructors are unused when every format feature is disabled.
+#[allow(dead_code)]
+impl Error {
+    pub(crate) fn encode(format: Format, source: impl Into<BoxError>) -> Self {
+        Error::Codec {
+            format,
+            operation: Operation::Encode,
+            path: None,
+            source: source.into(),
+        }
+    }
+
+    pub(crate) fn decode(format: Format, source: impl Into<BoxError>) -> Self {
+        Error::Codec {
+            format,
+            operation: Operation::Decode,
+            path: None,
+            source: source.into(),
+        }
+    }
+
+    pub(crate) fn hash(operation: Operation, source: impl Into<BoxError>) -> Self {
+        Error::Hash {
+            format: Format::Argon2,
+            operation,
+            source: source.into(),
+        }
+    }
+
+    pub(crate) fn decode_records(format: Format, errors: Vec<RecordError>) -> Self {
+        Error::Records {
+            format,
+            operation: Operation::Decode,
+            errors,
+        }
+    }
+
+    /// Sets the key of the field the error happened in.
+    pub(crate) fn at(mut self, key: &str) -> Self {
+        if let Error::Codec { path, .. } = &mut self {
+            *path = Some(key.to_string());
+        }
+        self
+    }
+
+    /// Returns the format or algorithm the error happened in.
+    pub fn format(&self) -> Format {
+        match self {
+            Error::Codec { format, .. }
+            | Error::Hash { format, .. }
+            | Error::Records { format, .. } => *format,
+        }
+    }
+
+    /// Returns the operation that failed.
+    pub fn operation(&self) -> Operation {
+        match self {
+            Error::Codec { operation, .. }
+            | Error::Hash { operation, .. }
+            | Error::Records { operation, .. } => *operation,
+        }
+    }
+
+    /// Returns the key of the field the error happened in, if it is known.
+    pub fn path(&self) -> Option<&str> {
+        match self {
+            Error::Codec { path, .. } => path.as_deref(),
+            Error::Hash { .. } | Error::Records { .. } => None,
+        }
+    }
+
+    /// Returns the failing records of a multi-record input, or an empty
+    /// slice for errors about a single value.
+    pub fn records(&self) -> &[RecordError] {
+        match self {
+            Error::Records { errors, .. } => errors,
+            Error::Codec { .. } | Error::Hash { .. } => &[],
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Codec {
+                format,
+                operation,
+                path,
+                source,
+            } => {
+                write!(f, "failed to {operation} {format}")?;
+                if let Some(path) = path {
+                    write!(f, " at `{path}`")?;
+                }
+                write!(f, ": {source}")
+            }
+            Error::Hash {
+                format,
+                operation,
+                source,
+            } => write!(f, "failed to {operation} {format} password hash: {source}"),
+            Error::Records {
+                format,
+                operation,
+                errors,
+            } => {
+                write!(
+                    f,
+                    "failed to {operation} {format}: {} invalid record(s)",
+                    errors.len()
+                )?;
+                for error in errors {
+                    write!(f, "; {error}")?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Codec { source, .. } | Error::Hash { source, .. } => Some(source.as_ref()),
+            Error::Records { errors, .. } => errors
+                .first()
+                .map(|error| error as &(dyn std::error::Error + 'static)),
+        }
+    }
+}
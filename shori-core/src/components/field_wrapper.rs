@@ -0,0 +1,271 @@
+use std::marker::PhantomData;
+
+use crate::{FieldTag, Traced};
+
+/// A single field moved out of a struct, returned by the methods of the
+/// field selector that `#[derive(Parser)]` generates.
+///
+/// `Tag` is the zero-sized type the derive emits for the field; it carries
+/// the field's name and key (see [`FieldTag`]).
+pub struct FieldWrapper<T, Tag>(T, PhantomData<fn() -> Tag>);
+
+impl<T, Tag: FieldTag> FieldWrapper<T, Tag> {
+    /// Wraps the value of the field tagged `Tag`.
+    pub fn new(value: T) -> Self {
+        Self(value, PhantomData)
+    }
+
+    /// Returns a reference to the field value.
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the field value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+
+    /// Returns the name of the field in the Rust source.
+    pub fn name(&self) -> &'static str {
+        Tag::NAME
+    }
+
+    /// Returns the key of the field in generated outputs.
+    pub fn key(&self) -> &'static str {
+        Tag::KEY
+    }
+}
+
+impl<T: Traced, Tag: FieldTag> FieldWrapper<T, Tag> {
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the field value into an `Arc`, wrapped in `ParseArc`.
+    pub fn arc(self) -> crate::ParseArc<T>
+    where
+        Tag::Capabilities: crate::capabilities::Arc,
+    {
+        crate::ParseArc::new(std::sync::Arc::new(self.0))
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the field value into a `tokio::sync::Mutex`, wrapped in `ParseTokioMutex`.
+    pub fn tokio_mutex(self) -> crate::ParseTokioMutex<T>
+    where
+        Tag::Capabilities: crate::capabilities::TokioMutex,
+    {
+        crate::ParseTokioMutex::new(tokio::sync::Mutex::new(self.0))
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the field value into a `std::sync::Mutex`, wrapped in `ParseMutex`.
+    pub fn mutex(self) -> crate::ParseMutex<T>
+    where
+        Tag::Capabilities: crate::capabilities::Mutex,
+    {
+        crate::ParseMutex::new(std::sync::Mutex::new(self.0))
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Boxes the field value, wrapped in `ParseBox`.
+    pub fn boxed(self) -> crate::ParseBox<T>
+    where
+        Tag::Capabilities: crate::capabilities::Box,
+    {
+        crate::ParseBox::new(Box::new(self.0))
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the field value in a `RefCell`, wrapped in `ParseRefCell`.
+    pub fn ref_cell(self) -> crate::ParseRefCell<T>
+    where
+        Tag::Capabilities: crate::capabilities::RefCell,
+    {
+        crate::ParseRefCell::new(std::cell::RefCell::new(self.0))
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the field value in an `UnsafeCell`, wrapped in `ParseUnsafeCell`.
+    pub fn unsafe_cell(self) -> crate::ParseUnsafeCell<T>
+    where
+        Tag::Capabilities: crate::capabilities::UnsafeCell,
+    {
+        crate::ParseUnsafeCell::new(std::cell::UnsafeCell::new(self.0))
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Initializes a `OnceCell` with the field value, wrapped in `ParseOnceCell`.
+    pub fn once_cell(self) -> crate::ParseOnceCell<T>
+    where
+        Tag::Capabilities: crate::capabilities::OnceCell,
+    {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        crate::ParseOnceCell::new(cell)
+    }
+
+    #[cfg(feature = "vec")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the field value into a single-element `Vec`, wrapped in `ParseVec`.
+    pub fn vec(self) -> crate::ParseVec<T>
+    where
+        Tag::Capabilities: crate::capabilities::Vec,
+    {
+        crate::ParseVec::new(vec![self.0])
+    }
+
+    #[cfg(feature = "bincode")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value into binary using `bincode`, wrapped in `ParseBin`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn bin(self) -> crate::Result<crate::ParseBin<T>>
+    where
+        T: bincode::Encode,
+        Tag::Capabilities: crate::capabilities::Bin,
+    {
+        crate::ParseBin::encode(&self.0).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value into a `serde_json::Value`, wrapped in `ParseJson`.
+    ///
+    /// # Errors
+    /// Returns an error if JSON serialization fails.
+    pub fn json(self) -> crate::Result<crate::ParseJson<T>>
+    where
+        T: serde::Serialize,
+        Tag::Capabilities: crate::capabilities::Json,
+    {
+        crate::ParseJson::encode(self.0).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value into a `serde_yaml::Value`, wrapped in `ParseYaml`.
+    ///
+    /// # Errors
+    /// Returns an error if YAML serialization fails.
+    pub fn yaml(self) -> crate::Result<crate::ParseYaml<T>>
+    where
+        T: serde::Serialize,
+        Tag::Capabilities: crate::capabilities::Yaml,
+    {
+        crate::ParseYaml::encode(self.0).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value into MessagePack, wrapped in `ParseMsgPack`.
+    ///
+    /// # Errors
+    /// Returns an error if MessagePack serialization fails.
+    pub fn msgpack(self) -> crate::Result<crate::ParseMsgPack<T>>
+    where
+        T: serde::Serialize,
+        Tag::Capabilities: crate::capabilities::MsgPack,
+    {
+        crate::ParseMsgPack::encode(&self.0).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "cbor")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value into CBOR, wrapped in `ParseCbor`.
+    ///
+    /// # Errors
+    /// Returns an error if CBOR serialization fails.
+    pub fn cbor(self) -> crate::Result<crate::ParseCbor<T>>
+    where
+        T: serde::Serialize,
+        Tag::Capabilities: crate::capabilities::Cbor,
+    {
+        crate::ParseCbor::encode(&self.0).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "postcard")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value with `postcard`, wrapped in `ParsePostcard`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn postcard(self) -> crate::Result<crate::ParsePostcard<T>>
+    where
+        T: serde::Serialize,
+        Tag::Capabilities: crate::capabilities::Postcard,
+    {
+        crate::ParsePostcard::encode(&self.0).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "ron")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value into a compact RON document, wrapped in `ParseRon`.
+    ///
+    /// # Errors
+    /// Returns an error if RON serialization fails.
+    pub fn ron(self) -> crate::Result<crate::ParseRon<T>>
+    where
+        T: serde::Serialize,
+        Tag::Capabilities: crate::capabilities::Ron,
+    {
+        crate::ParseRon::encode(&self.0).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "xml")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the field value into an XML document whose root element is
+    /// named by the serde key of the field, wrapped in `ParseXml`.
+    ///
+    /// # Errors
+    /// Returns an error if XML serialization fails.
+    pub fn xml(self) -> crate::Result<crate::ParseXml<T>>
+    where
+        T: serde::Serialize,
+        Tag::Capabilities: crate::capabilities::Xml,
+    {
+        crate::ParseXml::encode(&self.0, Tag::SERDE_NAME, &[]).map_err(|error| error.at(Tag::KEY))
+    }
+
+    #[cfg(feature = "hashmap")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Moves the field value into a single-entry map under the field key.
+    pub fn hashmap(self) -> crate::ParseHashMap
+    where
+        T: Send + Sync + 'static,
+        Tag::Capabilities: crate::capabilities::HashMap,
+    {
+        let mut map = std::collections::HashMap::new();
+        map.insert(
+            Tag::KEY.to_string(),
+            Box::new(self.0) as Box<dyn std::any::Any + Send + Sync>,
+        );
+        crate::ParseHashMap::new(map)
+    }
+}
+
+#[cfg(feature = "argon2")]
+impl<Tag: FieldTag> FieldWrapper<String, Tag> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Hashes the field value with Argon2id into a PHC string.
+    ///
+    /// # Errors
+    /// Returns an error if the Argon2 parameters are rejected or hashing fails.
+    pub fn argon2_hash(self) -> crate::Result<crate::Argon2Hash> {
+        crate::Argon2Hash::hash(&self.0)
+    }
+}
+
+impl<T: std::fmt::Debug, Tag: FieldTag> std::fmt::Debug for FieldWrapper<T, Tag> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("FieldWrapper")
+            .field(&Tag::NAME)
+            .field(&self.0)
+            .finish()
+    }
+}
@@ -0,0 +1,93 @@
+#[cfg(feature = "mutex")]
+pub mod parse_mutex;
+
+#[cfg(feature = "arc")]
+pub mod parse_arc;
+
+#[cfg(feature = "box")]
+pub mod parse_box;
+
+#[cfg(feature = "oncecell")]
+pub mod parse_once_cell;
+
+#[cfg(feature = "refcell")]
+pub mod parse_ref_cell;
+
+#[cfg(feature = "unsafecell")]
+pub mod parse_unsafe_cell;
+
+#[cfg(feature = "vec")]
+pub mod parse_vec;
+
+#[cfg(feature = "hashmap")]
+pub mod parse_hash_map;
+
+#[cfg(feature = "tokio")]
+pub mod parse_tokio_mutex;
+
+#[cfg(feature = "serde_json")]
+pub mod parse_json;
+
+#[cfg(feature = "bincode")]
+pub mod parse_bin;
+
+#[cfg(feature = "toml")]
+pub mod parse_toml;
+
+#[cfg(feature = "argon2")]
+pub mod argon2_hash;
+
+#[cfg(feature = "serde_yaml")]
+pub mod parse_yaml;
+
+#[cfg(feature = "msgpack")]
+pub mod parse_msgpack;
+
+#[cfg(feature = "cbor")]
+pub mod parse_cbor;
+
+#[cfg(feature = "ron")]
+pub mod parse_ron;
+
+#[cfg(feature = "csv")]
+pub mod parse_csv;
+
+#[cfg(feature = "xml")]
+pub mod parse_xml;
+
+#[cfg(feature = "postcard")]
+pub mod parse_postcard;
+
+#[cfg(all(feature = "serde_json", feature = "vec"))]
+pub mod parse_jsonl;
+
+#[cfg(feature = "query_string")]
+pub mod parse_query_string;
+
+#[cfg(feature = "env")]
+pub mod parse_env;
+
+#[cfg(feature = "ini")]
+pub mod parse_ini;
+
+#[cfg(any(feature = "env", feature = "ini"))]
+pub(crate) mod text_tree;
+
+#[cfg(feature = "bson")]
+pub mod parse_bson;
+
+pub mod capabilities;
+
+pub mod error;
+
+pub mod field_wrapper;
+
+pub mod parse;
+
+pub mod parsable;
+
+pub mod prelude;
+
+pub mod traced;
+
+pub mod traits;
@@ -0,0 +1,226 @@
+use crate::{FieldInfo, Metadata, Parse, Traced};
+
+/// Common interface of every `#[derive(Parser)]` type, so that code can be
+/// written once for all of them:
+///
+/// ```ignore
+/// use shori_core::{Parsable, capabilities};
+///
+/// fn persist<T>(value: T) -> Vec<u8>
+/// where
+///     T: Parsable<Capabilities: capabilities::Json> + serde::Serialize,
+/// {
+///     value.json().unwrap().to_string().into_bytes()
+/// }
+/// ```
+///
+/// It is implemented for every type implementing [`Metadata`], which the
+/// derive emits; the associated wrapper types are the ones returned by the
+/// corresponding [`Parse`] conversions.
+pub trait Parsable: Metadata + Traced + Sized {
+    /// JSON wrapper returned by [`Parsable::json`].
+    #[cfg(feature = "serde_json")]
+    type Json;
+    /// Binary wrapper returned by [`Parsable::bin`].
+    #[cfg(feature = "bincode")]
+    type Bin;
+    /// TOML wrapper returned by [`Parsable::toml`].
+    #[cfg(feature = "toml")]
+    type Toml;
+
+    /// Wraps the value in its `Parse` wrapper.
+    fn parse(self) -> Parse<Self>;
+
+    /// Returns the name of the type.
+    fn type_name() -> &'static str {
+        Self::NAME
+    }
+
+    /// Returns the metadata of every field that is not skipped.
+    fn fields() -> &'static [FieldInfo] {
+        Self::FIELDS
+    }
+
+    /// Serializes the value into its JSON wrapper.
+    ///
+    /// # Errors
+    /// Returns an error if JSON serialization fails.
+    #[cfg(feature = "serde_json")]
+    fn json(self) -> crate::Result<Self::Json>
+    where
+        Self: serde::Serialize + crate::Metadata<Capabilities: crate::capabilities::Json>;
+
+    /// Encodes the value into its binary wrapper.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    #[cfg(feature = "bincode")]
+    fn bin(self) -> crate::Result<Self::Bin>
+    where
+        Self: bincode::Encode + crate::Metadata<Capabilities: crate::capabilities::Bin>;
+
+    /// Serializes the value into its TOML wrapper.
+    ///
+    /// # Errors
+    /// Returns an error if TOML serialization fails.
+    #[cfg(feature = "toml")]
+    fn toml(self) -> crate::Result<Self::Toml>
+    where
+        Self: serde::Serialize + crate::Metadata<Capabilities: crate::capabilities::Toml>;
+
+    /// Reads comma-separated CSV with a header row, one value per row.
+    ///
+    /// Columns are matched to fields by the keys serde reads, honoring
+    /// `#[serde(rename)]`; skipped fields need `#[serde(default)]`.
+    ///
+    /// # Errors
+    /// Returns an error listing every invalid row, with its line and the
+    /// failing column when known.
+    #[cfg(feature = "csv")]
+    fn from_csv<R: std::io::Read>(reader: R) -> crate::Result<crate::ParseVec<Self>>
+    where
+        Self: serde::de::DeserializeOwned + crate::Metadata<Capabilities: crate::capabilities::Csv>,
+    {
+        Self::from_csv_with(reader, &crate::CsvOptions::default())
+    }
+
+    /// Reads CSV with the given delimiter, like [`Parsable::from_csv`].
+    ///
+    /// # Errors
+    /// Returns an error listing every invalid row, with its line and the
+    /// failing column when known.
+    #[cfg(feature = "csv")]
+    fn from_csv_with<R: std::io::Read>(
+        reader: R,
+        options: &crate::CsvOptions,
+    ) -> crate::Result<crate::ParseVec<Self>>
+    where
+        Self: serde::de::DeserializeOwned + crate::Metadata<Capabilities: crate::capabilities::Csv>,
+    {
+        crate::components::parse_csv::decode(reader, options)
+    }
+
+    /// Lazily reads JSON Lines, one value per non-blank line; errors carry
+    /// the line number, see [`JsonLines`](crate::JsonLines).
+    #[cfg(all(feature = "serde_json", feature = "vec"))]
+    fn jsonl_reader<R: std::io::Read>(reader: R) -> crate::JsonLines<Self, R>
+    where
+        Self:
+            serde::de::DeserializeOwned + crate::Metadata<Capabilities: crate::capabilities::Jsonl>,
+    {
+        crate::JsonLines::new(reader)
+    }
+
+    /// Reads a URL query string such as `id=1&tag=a&tag=b&db[host]=x`,
+    /// parsing every value into the type of its field.
+    ///
+    /// # Errors
+    /// Returns an error if a key conflicts with another one or a value does
+    /// not parse into its field type.
+    #[cfg(feature = "query_string")]
+    fn from_query_string(query: &str) -> crate::Result<Self>
+    where
+        Self: serde::de::DeserializeOwned
+            + crate::Metadata<Capabilities: crate::capabilities::QueryString>,
+    {
+        crate::components::parse_query_string::decode(query)
+    }
+
+    /// Reads the process environment: `APP_NAME` fills the field serde keys
+    /// `name` for the prefix `APP_`, and `APP_DB__HOST` the `host` field of
+    /// the nested `db` struct. Names are matched ignoring case and every
+    /// value is parsed into its field type with `FromStr`; sequences are
+    /// comma-separated.
+    ///
+    /// # Errors
+    /// Returns an error listing every missing or invalid variable.
+    #[cfg(feature = "env")]
+    fn from_env(prefix: &str) -> crate::Result<Self>
+    where
+        Self: serde::de::DeserializeOwned + crate::Metadata<Capabilities: crate::capabilities::Env>,
+    {
+        crate::components::parse_env::decode(prefix, crate::components::parse_env::vars())
+    }
+
+    /// Like [`Parsable::from_env`], reading the given variables instead of
+    /// the process environment.
+    ///
+    /// # Errors
+    /// Returns an error listing every missing or invalid variable.
+    #[cfg(feature = "env")]
+    fn from_env_map(
+        prefix: &str,
+        vars: std::collections::HashMap<String, String>,
+    ) -> crate::Result<Self>
+    where
+        Self: serde::de::DeserializeOwned + crate::Metadata<Capabilities: crate::capabilities::Env>,
+    {
+        crate::components::parse_env::decode(prefix, vars)
+    }
+
+    /// Reads an INI document: global keys fill the fields by serde key and
+    /// `[sections]` the nested structs, parsing every value into the type of
+    /// its field.
+    ///
+    /// # Errors
+    /// Returns an error listing every malformed line and every missing or
+    /// invalid key.
+    #[cfg(feature = "ini")]
+    fn from_ini(document: &str) -> crate::Result<Self>
+    where
+        Self: serde::de::DeserializeOwned + crate::Metadata<Capabilities: crate::capabilities::Ini>,
+    {
+        crate::components::parse_ini::decode(document)
+    }
+
+    /// Reads an XML document written by [`Parse::xml`], whatever the name of
+    /// its root element.
+    ///
+    /// # Errors
+    /// Returns an error if the document is not well-formed or does not match
+    /// the type.
+    #[cfg(feature = "xml")]
+    fn from_xml(document: &str) -> crate::Result<Self>
+    where
+        Self: serde::de::DeserializeOwned + crate::Metadata<Capabilities: crate::capabilities::Xml>,
+    {
+        crate::components::parse_xml::decode(document, Self::FIELDS)
+    }
+}
+
+impl<T: Metadata + Traced> Parsable for T {
+    #[cfg(feature = "serde_json")]
+    type Json = crate::ParseJson<T>;
+    #[cfg(feature = "bincode")]
+    type Bin = crate::ParseBin<T>;
+    #[cfg(feature = "toml")]
+    type Toml = crate::ParseToml<T>;
+
+    fn parse(self) -> Parse<Self> {
+        Parse::new(self)
+    }
+
+    #[cfg(feature = "serde_json")]
+    fn json(self) -> crate::Result<Self::Json>
+    where
+        Self: serde::Serialize + crate::Metadata<Capabilities: crate::capabilities::Json>,
+    {
+        Parse::new(self).json()
+    }
+
+    #[cfg(feature = "bincode")]
+    fn bin(self) -> crate::Result<Self::Bin>
+    where
+        Self: bincode::Encode + crate::Metadata<Capabilities: crate::capabilities::Bin>,
+    {
+        Parse::new(self).bin()
+    }
+
+    #[cfg(feature = "toml")]
+    fn toml(self) -> crate::Result<Self::Toml>
+    where
+        Self: serde::Serialize + crate::Metadata<Capabilities: crate::capabilities::Toml>,
+    {
+        Parse::new(self).toml()
+    }
+}
@@ -0,0 +1,462 @@
+use crate::{Fields, Traced, Variants};
+
+#[derive(Debug)]
+/// Wrapper type that provides parsing and transformation utilities
+/// for the underlying type.
+///
+/// Created by the `parse()` method that `#[derive(Parser)]` adds to a type.
+pub struct Parse<T>(T);
+
+impl<T> Parse<T> {
+    /// Wraps `value`.
+    pub fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub fn get(&self) -> &T {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Traced> Parse<T> {
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the inner struct into an `Arc`, wrapped in `ParseArc`.
+    ///
+    /// Useful for thread-safe shared ownership.
+    pub fn arc(self) -> crate::ParseArc<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Arc>,
+    {
+        crate::ParseArc::new(std::sync::Arc::new(self.0))
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts into a `tokio::sync::Mutex`, wrapped in `ParseTokioMutex`.
+    ///
+    /// Useful for safe mutation across async tasks.
+    pub fn tokio_mutex(self) -> crate::ParseTokioMutex<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::TokioMutex>,
+    {
+        crate::ParseTokioMutex::new(tokio::sync::Mutex::new(self.0))
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts into a `std::sync::Mutex` wrapped in `ParseMutex`.
+    ///
+    /// Use this for interior mutability in synchronous code.
+    pub fn mutex(self) -> crate::ParseMutex<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Mutex>,
+    {
+        crate::ParseMutex::new(std::sync::Mutex::new(self.0))
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Boxes the inner struct into `Box<T>`, wrapped in `ParseBox`.
+    pub fn boxed(self) -> crate::ParseBox<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Box>,
+    {
+        crate::ParseBox::new(Box::new(self.0))
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the struct in a `RefCell`, allowing interior mutability in single-threaded contexts.
+    pub fn ref_cell(self) -> crate::ParseRefCell<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::RefCell>,
+    {
+        crate::ParseRefCell::new(std::cell::RefCell::new(self.0))
+    }
+
+    #[cfg(feature = "bincode")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into binary using `bincode`, wrapped in `ParseBin`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn bin(self) -> crate::Result<crate::ParseBin<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Bin>,
+        T: bincode::Encode,
+    {
+        crate::ParseBin::encode(&self.0)
+    }
+
+    #[cfg(feature = "postcard")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct with `postcard`, wrapped in `ParsePostcard`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn postcard(self) -> crate::Result<crate::ParsePostcard<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Postcard>,
+        T: serde::Serialize,
+    {
+        crate::ParsePostcard::encode(&self.0)
+    }
+
+    #[cfg(feature = "postcard")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct with `postcard` into a COBS frame ending with a
+    /// zero byte, wrapped in `ParsePostcard`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn postcard_cobs(self) -> crate::Result<crate::ParsePostcard<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Postcard>,
+        T: serde::Serialize,
+    {
+        crate::ParsePostcard::encode_cobs(&self.0)
+    }
+
+    #[cfg(feature = "postcard")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct with `postcard` into `buffer`, without
+    /// allocating, and returns the number of bytes written at its start.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails or `buffer` is too small.
+    pub fn postcard_into(&self, buffer: &mut [u8]) -> crate::Result<usize>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Postcard>,
+        T: serde::Serialize,
+    {
+        postcard::to_slice(&self.0, buffer)
+            .map(|written| written.len())
+            .map_err(|error| crate::Error::encode(crate::Format::Postcard, error))
+    }
+
+    #[cfg(feature = "postcard")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct with `postcard` into a COBS frame in `buffer`,
+    /// without allocating, and returns the number of bytes written at its
+    /// start.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails or `buffer` is too small.
+    pub fn postcard_cobs_into(&self, buffer: &mut [u8]) -> crate::Result<usize>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Postcard>,
+        T: serde::Serialize,
+    {
+        postcard::to_slice_cobs(&self.0, buffer)
+            .map(|written| written.len())
+            .map_err(|error| crate::Error::encode(crate::Format::Postcard, error))
+    }
+
+    #[cfg(feature = "toml")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into a `toml::Value`, wrapped in `ParseToml`.
+    ///
+    /// # Errors
+    /// Returns an error if TOML serialization or parsing fails.
+    pub fn toml(self) -> crate::Result<crate::ParseToml<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Toml>,
+        T: serde::Serialize,
+    {
+        crate::ParseToml::encode(&self.0)
+    }
+
+    #[cfg(feature = "ron")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into a compact RON document, wrapped in `ParseRon`.
+    ///
+    /// # Errors
+    /// Returns an error if RON serialization fails.
+    pub fn ron(self) -> crate::Result<crate::ParseRon<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Ron>,
+        T: serde::Serialize,
+    {
+        crate::ParseRon::encode(&self.0)
+    }
+
+    #[cfg(feature = "ron")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into an indented, multi-line RON document,
+    /// wrapped in `ParseRon`.
+    ///
+    /// # Errors
+    /// Returns an error if RON serialization fails.
+    pub fn ron_pretty(self) -> crate::Result<crate::ParseRon<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Ron>,
+        T: serde::Serialize,
+    {
+        crate::ParseRon::encode_pretty(&self.0)
+    }
+
+    #[cfg(feature = "xml")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into an XML document whose root element is
+    /// named after the type, wrapped in `ParseXml`.
+    ///
+    /// Fields are child elements named by their serde key, except
+    /// `#[parser(xml_attribute)]` fields, which are attributes of the root.
+    ///
+    /// # Errors
+    /// Returns an error if XML serialization fails or an attribute field is
+    /// not a scalar value.
+    pub fn xml(self) -> crate::Result<crate::ParseXml<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Xml>,
+        T: serde::Serialize,
+    {
+        crate::ParseXml::encode(&self.0, T::NAME, T::FIELDS)
+    }
+
+    #[cfg(feature = "xml")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into an XML document with the given root
+    /// element name, wrapped in `ParseXml`.
+    ///
+    /// # Errors
+    /// Returns an error if XML serialization fails or an attribute field is
+    /// not a scalar value.
+    pub fn xml_with_root(self, root: &str) -> crate::Result<crate::ParseXml<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Xml>,
+        T: serde::Serialize,
+    {
+        crate::ParseXml::encode(&self.0, root, T::FIELDS)
+    }
+
+    #[cfg(feature = "query_string")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into a URL query string keyed by field key,
+    /// wrapped in `ParseQueryString`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn query_string(self) -> crate::Result<crate::ParseQueryString<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::QueryString>,
+        T: serde::Serialize,
+    {
+        crate::ParseQueryString::encode(&self.0, T::FIELDS)
+    }
+
+    #[cfg(feature = "ini")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into an INI document, nested structs as
+    /// sections, wrapped in `ParseIni`.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails or a value cannot be written
+    /// as INI, such as a multi-line string or a list of structs.
+    pub fn ini(self) -> crate::Result<crate::ParseIni<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Ini>,
+        T: serde::Serialize,
+    {
+        crate::ParseIni::encode(&self.0, T::FIELDS)
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into a `serde_json::Value`, wrapped in `ParseJson`.
+    ///
+    /// # Errors
+    /// Returns an error if JSON serialization fails.
+    pub fn json(self) -> crate::Result<crate::ParseJson<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Json>,
+        T: serde::Serialize,
+    {
+        crate::ParseJson::encode(self.0)
+    }
+
+    #[cfg(feature = "serde_yaml")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into a `serde_yaml::Value`, wrapped in `ParseYaml`.
+    ///
+    /// # Errors
+    /// Returns an error if YAML serialization fails.
+    pub fn yaml(self) -> crate::Result<crate::ParseYaml<T>>
+    where
+        T: serde::Serialize,
+        T: crate::Metadata<Capabilities: crate::capabilities::Yaml>,
+    {
+        crate::ParseYaml::encode(self.0)
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into MessagePack, with fields as a map keyed by
+    /// name, wrapped in `ParseMsgPack`.
+    ///
+    /// # Errors
+    /// Returns an error if MessagePack serialization fails.
+    pub fn msgpack(self) -> crate::Result<crate::ParseMsgPack<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::MsgPack>,
+        T: serde::Serialize,
+    {
+        crate::ParseMsgPack::encode(&self.0)
+    }
+
+    #[cfg(feature = "msgpack")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into MessagePack, with fields as a positional
+    /// array, wrapped in `ParseMsgPack`.
+    ///
+    /// Smaller than `msgpack()`, but the reader must know the field order.
+    ///
+    /// # Errors
+    /// Returns an error if MessagePack serialization fails.
+    pub fn msgpack_compact(self) -> crate::Result<crate::ParseMsgPack<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::MsgPack>,
+        T: serde::Serialize,
+    {
+        crate::ParseMsgPack::encode_compact(&self.0)
+    }
+
+    #[cfg(feature = "bson")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into a BSON document, wrapped in `ParseBson`.
+    ///
+    /// # Errors
+    /// Returns an error if BSON serialization fails, e.g. for a `u64` above
+    /// `i64::MAX`.
+    pub fn bson(self) -> crate::Result<crate::ParseBson<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Bson>,
+        T: serde::Serialize,
+    {
+        crate::ParseBson::encode(&self.0)
+    }
+
+    #[cfg(feature = "cbor")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into CBOR, wrapped in `ParseCbor`.
+    ///
+    /// # Errors
+    /// Returns an error if CBOR serialization fails.
+    pub fn cbor(self) -> crate::Result<crate::ParseCbor<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Cbor>,
+        T: serde::Serialize,
+    {
+        crate::ParseCbor::encode(&self.0)
+    }
+
+    #[cfg(feature = "cbor")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Serializes the struct into deterministically encoded CBOR
+    /// (RFC 8949, section 4.2.1), wrapped in `ParseCbor`.
+    ///
+    /// Map keys are sorted and every length is definite, so the bytes are
+    /// stable enough to be hashed or signed.
+    ///
+    /// # Errors
+    /// Returns an error if CBOR serialization fails.
+    pub fn cbor_deterministic(self) -> crate::Result<crate::ParseCbor<T>>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Cbor>,
+        T: serde::Serialize,
+    {
+        crate::ParseCbor::encode_deterministic(&self.0)
+    }
+
+    #[cfg(feature = "vec")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the struct into a `Vec<T>`, containing a single element.
+    ///
+    /// Useful for APIs that expect list input.
+    pub fn vec(self) -> crate::ParseVec<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Vec>,
+    {
+        crate::ParseVec::new(vec![self.0])
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the struct in an `UnsafeCell`.
+    ///
+    /// Allows unchecked interior mutability.
+    pub fn unsafe_cell(self) -> crate::ParseUnsafeCell<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::UnsafeCell>,
+    {
+        crate::ParseUnsafeCell::new(std::cell::UnsafeCell::new(self.0))
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Initializes a `OnceCell` with the struct.
+    ///
+    /// The value is set once and subsequent attempts are ignored.
+    pub fn once_cell(self) -> crate::ParseOnceCell<T>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::OnceCell>,
+    {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        crate::ParseOnceCell::new(cell)
+    }
+}
+
+impl<T: Fields> Parse<T> {
+    /// Returns the field selector of the struct, with one method per field.
+    pub fn field(self) -> T::Selector {
+        T::selector(self)
+    }
+
+    #[cfg(feature = "tuple")]
+    /// Returns a tuple of references to all the struct's fields.
+    ///
+    /// Useful for destructuring or pattern matching.
+    pub fn tuple(&self) -> T::Tuple<'_>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Tuple>,
+    {
+        self.0.tuple()
+    }
+}
+
+#[cfg(feature = "hashmap")]
+impl<T: crate::IntoHashMap + Traced> Parse<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the struct fields into a `HashMap<String, Box<dyn Any + Send + Sync>>`.
+    ///
+    /// This enables dynamic access to fields by their name.
+    pub fn hashmap(self) -> crate::ParseHashMap
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::HashMap>,
+    {
+        crate::ParseHashMap::new(self.0.into_hashmap())
+    }
+}
+
+impl<T: Variants> Parse<T> {
+    /// Names of all the variants of the enum, in declaration order.
+    pub const VARIANTS: &'static [&'static str] = T::VARIANTS;
+
+    /// Returns the variant selector of the enum.
+    pub fn variant(self) -> T::Selector {
+        T::selector(self)
+    }
+
+    /// Returns the name of the variant held by the wrapped value.
+    pub fn variant_name(&self) -> &'static str {
+        self.0.variant_name()
+    }
+}
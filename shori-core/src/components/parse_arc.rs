@@ -0,0 +1,55 @@
+use crate::Traced;
+
+/// A wrapper around `Arc<T>` that provides helper methods to
+/// convert into common smart pointer containers.
+#[derive(Debug)]
+pub struct ParseArc<T>(std::sync::Arc<T>);
+
+impl<T> ParseArc<T> {
+    pub(crate) fn new(value: std::sync::Arc<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the inner `Arc<T>`.
+    pub fn as_inner(&self) -> &std::sync::Arc<T> {
+        &self.0
+    }
+}
+
+impl<T: Traced> ParseArc<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns the inner `Arc<T>` value.
+    pub fn get(self) -> std::sync::Arc<T> {
+        self.0
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the `Arc<T>` into a `Box<Arc<T>>`.
+    pub fn boxed(self) -> Box<std::sync::Arc<T>> {
+        Box::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the `Arc<T>` inside a `RefCell` for interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<std::sync::Arc<T>> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the `Arc<T>` in a `OnceCell` for one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<std::sync::Arc<T>> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the `Arc<T>` inside an `UnsafeCell`, allowing low-level mutability.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<std::sync::Arc<T>> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+}
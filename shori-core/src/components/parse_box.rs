@@ -0,0 +1,69 @@
+use crate::Traced;
+
+#[derive(Debug)]
+/// A wrapper around `Box<T>` providing helper methods to
+/// convert into various smart pointer containers.
+pub struct ParseBox<T>(Box<T>);
+
+impl<T> ParseBox<T> {
+    pub(crate) fn new(value: Box<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the boxed value.
+    pub fn as_inner(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: Traced> ParseBox<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns the inner `Box<T>`.
+    pub fn get(self) -> Box<T> {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the boxed value into an `Arc<Box<T>>`.
+    pub fn arc(self) -> std::sync::Arc<Box<T>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the boxed value in a Tokio async mutex.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<Box<T>> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the boxed value in a standard mutex.
+    pub fn mutex(self) -> std::sync::Mutex<Box<T>> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the boxed value in a `RefCell` for interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<Box<T>> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the boxed value in an `UnsafeCell`.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<Box<T>> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the boxed value in a `OnceCell` for one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<Box<T>> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+}
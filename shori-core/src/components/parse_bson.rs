@@ -0,0 +1,139 @@
+use std::marker::PhantomData;
+
+use crate::Traced;
+
+#[derive(Debug)]
+/// A BSON document serialized from a `T`, which can be decoded back into it.
+///
+/// Fields are keyed by their Rust name, as with the other serde formats;
+/// `bytes()` gives the raw document as stored by document databases.
+pub struct ParseBson<T>(bson::Document, PhantomData<fn() -> T>);
+
+impl<T> ParseBson<T> {
+    pub(crate) fn new(value: bson::Document) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        bson::to_document(value)
+            .map(Self::new)
+            .map_err(|error| crate::Error::encode(crate::Format::Bson, error))
+    }
+}
+
+impl<T: Traced> ParseBson<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns a reference to the BSON document.
+    pub fn get(&self) -> &bson::Document {
+        &self.0
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseBson` and returns the BSON document.
+    pub fn document(self) -> bson::Document {
+        self.0
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Writes the document as raw BSON bytes.
+    ///
+    /// # Errors
+    /// Returns an error if the document cannot be written, e.g. when it is
+    /// larger than BSON allows.
+    pub fn bytes(&self) -> crate::Result<Vec<u8>> {
+        let mut bytes = Vec::new();
+        self.0
+            .to_writer(&mut bytes)
+            .map_err(|error| crate::Error::encode(crate::Format::Bson, error))?;
+        Ok(bytes)
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the document into an `Arc<bson::Document>`.
+    pub fn arc(self) -> std::sync::Arc<bson::Document> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the document in a Tokio `Mutex`.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<bson::Document> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the document in a standard `Mutex`.
+    pub fn mutex(self) -> std::sync::Mutex<bson::Document> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the document in a `RefCell` for interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<bson::Document> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the document in an `UnsafeCell`.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<bson::Document> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the document in a `OnceCell` for one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<bson::Document> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode the struct instance from the document.
+    ///
+    /// # Errors
+    ///
+    /// Returns a decoding error if the document does not match the struct.
+    pub fn from(&self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.from_document(self.0.clone())
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode the struct instance from the given document, e.g.
+    /// one read from a database.
+    ///
+    /// # Errors
+    ///
+    /// Returns a decoding error if the document does not match the struct.
+    pub fn from_document(&self, document: bson::Document) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        bson::from_document(document)
+            .map_err(|error| crate::Error::decode(crate::Format::Bson, error))
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode the struct instance from raw BSON bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns a decoding error if the bytes are not a valid BSON document
+    /// or do not match the struct.
+    pub fn from_bytes(&self, bytes: &[u8]) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        bson::from_slice(bytes).map_err(|error| crate::Error::decode(crate::Format::Bson, error))
+    }
+}
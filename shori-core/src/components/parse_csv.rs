@@ -0,0 +1,185 @@
+use crate::{ParseVec, RecordError, Traced};
+
+/// When fields are wrapped in quotes by [`ParseVec::csv_with`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CsvQuoting {
+    /// Only fields containing the delimiter, a quote or a line break.
+    #[default]
+    Necessary,
+    /// Every field.
+    Always,
+    /// Every field that is not a number.
+    NonNumeric,
+    /// No field; the output may not be readable back.
+    Never,
+}
+
+/// Delimiter and quoting used to write and read CSV.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CsvOptions {
+    delimiter: u8,
+    quoting: CsvQuoting,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self {
+            delimiter: b',',
+            quoting: CsvQuoting::Necessary,
+        }
+    }
+}
+
+impl CsvOptions {
+    /// Comma-separated, quoting only where needed.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the field delimiter, e.g. `b';'` or `b'\t'`.
+    pub fn delimiter(mut self, delimiter: u8) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Sets when fields are quoted on output.
+    pub fn quoting(mut self, quoting: CsvQuoting) -> Self {
+        self.quoting = quoting;
+        self
+    }
+
+    fn writer(&self) -> csv::WriterBuilder {
+        let mut builder = csv::WriterBuilder::new();
+        builder
+            .delimiter(self.delimiter)
+            .quote_style(match self.quoting {
+                CsvQuoting::Necessary => csv::QuoteStyle::Necessary,
+                CsvQuoting::Always => csv::QuoteStyle::Always,
+                CsvQuoting::NonNumeric => csv::QuoteStyle::NonNumeric,
+                CsvQuoting::Never => csv::QuoteStyle::Never,
+            });
+        builder
+    }
+
+    fn reader(&self) -> csv::ReaderBuilder {
+        let mut builder = csv::ReaderBuilder::new();
+        builder.delimiter(self.delimiter);
+        builder
+    }
+}
+
+impl<T: Traced> ParseVec<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Writes the elements as comma-separated CSV: a header of field keys,
+    /// then one row per element.
+    ///
+    /// Columns follow the declaration order of the fields; `#[parser(skip)]`
+    /// fields are left out and headers are the keys serde writes, honoring
+    /// `#[serde(rename)]` and `#[serde(rename_all)]`.
+    ///
+    /// # Errors
+    /// Returns an error if an element cannot be serialized, is not a struct
+    /// with named fields, leaves a field out, or has a field that is not a
+    /// scalar (string, number, bool or unit enum variant).
+    pub fn csv(&self) -> crate::Result<String>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Csv>,
+        T: serde::Serialize,
+    {
+        self.csv_with(&CsvOptions::default())
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Writes the elements as CSV with the given delimiter and quoting.
+    ///
+    /// # Errors
+    /// Returns an error if an element cannot be serialized, is not a struct
+    /// with named fields, leaves a field out, or has a field that is not a
+    /// scalar (string, number, bool or unit enum variant).
+    pub fn csv_with(&self, options: &CsvOptions) -> crate::Result<String>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Csv>,
+        T: serde::Serialize,
+    {
+        let encode = |error: crate::BoxError| crate::Error::encode(crate::Format::Csv, error);
+        let mut writer = options.writer().from_writer(Vec::new());
+        writer
+            .write_record(T::FIELDS.iter().map(|field| field.serde_name))
+            .map_err(|error| encode(error.into()))?;
+        for element in self.as_inner() {
+            let value = serde_json::to_value(element).map_err(|error| encode(error.into()))?;
+            let serde_json::Value::Object(object) = value else {
+                return Err(encode(
+                    "only structs with named fields can be written as CSV".into(),
+                ));
+            };
+            let mut row = Vec::with_capacity(T::FIELDS.len());
+            for field in T::FIELDS {
+                let cell = match object.get(field.serde_name) {
+                    Some(serde_json::Value::Null) => String::new(),
+                    Some(serde_json::Value::String(text)) => text.clone(),
+                    Some(serde_json::Value::Bool(flag)) => flag.to_string(),
+                    Some(serde_json::Value::Number(number)) => number.to_string(),
+                    Some(_) => {
+                        return Err(encode("not a scalar value".into()).at(field.serde_name));
+                    }
+                    None => {
+                        return Err(
+                            encode("the field was not serialized".into()).at(field.serde_name)
+                        );
+                    }
+                };
+                row.push(cell);
+            }
+            writer
+                .write_record(&row)
+                .map_err(|error| encode(error.into()))?;
+        }
+        let bytes = writer
+            .into_inner()
+            .map_err(|error| encode(error.into_error().into()))?;
+        String::from_utf8(bytes).map_err(|error| encode(error.into()))
+    }
+}
+
+/// Reads CSV with a header row into `T`s, matching columns to fields by
+/// their serde key and collecting the failure of every invalid row.
+pub(crate) fn decode<T, R>(reader: R, options: &CsvOptions) -> crate::Result<ParseVec<T>>
+where
+    T: serde::de::DeserializeOwned,
+    R: std::io::Read,
+{
+    let mut reader = options.reader().from_reader(reader);
+    let keys = reader
+        .headers()
+        .map_err(|error| crate::Error::decode(crate::Format::Csv, error))?
+        .clone();
+
+    let mut elements = Vec::new();
+    let mut errors = Vec::new();
+    for record in reader.records() {
+        let result = record.and_then(|record| record.deserialize::<T>(Some(&keys)));
+        match result {
+            Ok(element) => elements.push(element),
+            Err(error) => errors.push(record_error(error, &keys)),
+        }
+    }
+    if errors.is_empty() {
+        Ok(ParseVec::new(elements))
+    } else {
+        Err(crate::Error::decode_records(crate::Format::Csv, errors))
+    }
+}
+
+fn record_error(error: csv::Error, keys: &csv::StringRecord) -> RecordError {
+    let line = error.position().map(csv::Position::line);
+    match error.kind() {
+        csv::ErrorKind::Deserialize { err, .. } => {
+            let key = err
+                .field()
+                .and_then(|index| keys.get(usize::try_from(index).ok()?));
+            RecordError::new(line, key, err.kind().to_string())
+        }
+        _ => RecordError::new(line, None, error),
+    }
+}
@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+
+use crate::RecordError;
+use crate::components::text_tree::{self, Naming, Node};
+
+const NAMING: Naming = Naming {
+    noun: "variable",
+    separator: "__",
+    uppercase: true,
+};
+
+/// Returns the variables of the process environment; names and values that
+/// are not valid unicode are skipped and replaced lossily, respectively.
+pub(crate) fn vars() -> HashMap<String, String> {
+    std::env::vars_os()
+        .filter_map(|(name, value)| {
+            Some((
+                name.into_string().ok()?,
+                value.to_string_lossy().into_owned(),
+            ))
+        })
+        .collect()
+}
+
+/// Deserializes the variables starting with `prefix` into a `T`: the rest
+/// of the name is the serde key of a field, all ignoring case, and `__`
+/// separates the fields of nested structs (`APP_DB__HOST`).
+///
+/// Every missing or invalid variable is reported.
+pub(crate) fn decode<T>(prefix: &str, vars: HashMap<String, String>) -> crate::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut vars: Vec<(String, String)> = vars
+        .into_iter()
+        .filter(|(name, _)| {
+            name.get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(prefix))
+        })
+        .collect();
+    vars.sort();
+
+    let mut root = Node::Map(Vec::new());
+    let mut errors = Vec::new();
+    for (name, value) in &vars {
+        let path: Vec<&str> = name[prefix.len()..].split("__").collect();
+        if path.iter().any(|segment| segment.is_empty()) {
+            continue;
+        }
+        if let Err(error) = root.insert(&path, value, NAMING.noun) {
+            errors.push(RecordError::new(None, Some(name), error));
+        }
+    }
+
+    text_tree::deserialize(&root, prefix, NAMING, crate::Format::Env, errors)
+}
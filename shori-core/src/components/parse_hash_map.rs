@@ -0,0 +1,88 @@
+use std::any::Any;
+use std::collections::HashMap;
+
+use crate::Traced;
+
+/// HashMap with String keys and boxed dynamic values that implement Send + Sync.
+type HashMapResp = HashMap<String, Box<dyn Any + Send + Sync>>;
+
+/// Wrapper around a HashMap storing heterogeneous values.
+pub struct ParseHashMap(HashMapResp);
+
+impl ParseHashMap {
+    pub(crate) fn new(map: HashMapResp) -> Self {
+        Self(map)
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to retrieve a reference to a value of type `T` associated
+    /// with the given key. Returns `None` if the key is not found or
+    /// if the stored value is of a different type.
+    pub fn get<T: 'static + Traced>(&self, key: &str) -> Option<&T> {
+        self.0.get(key).and_then(|v| v.downcast_ref::<T>())
+    }
+
+    /// Returns `true` if the map holds a value for `key`.
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.0.contains_key(key)
+    }
+
+    /// Returns the keys of the map, in arbitrary order.
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Returns a reference to the inner HashMap.
+    pub fn as_inner(&self) -> &HashMapResp {
+        &self.0
+    }
+
+    /// Consumes the wrapper and returns the inner HashMap.
+    pub fn into_inner(self) -> HashMapResp {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the internal HashMap into an Arc for shared ownership.
+    pub fn arc(self) -> std::sync::Arc<HashMapResp> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Boxes the internal HashMap.
+    pub fn boxed(self) -> Box<HashMapResp> {
+        Box::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the internal HashMap in a RefCell for interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<HashMapResp> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the internal HashMap in an UnsafeCell.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<HashMapResp> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the internal HashMap in a OnceCell for one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<HashMapResp> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+}
+
+impl std::fmt::Debug for ParseHashMap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let keys: Vec<&String> = self.0.keys().collect();
+        f.debug_struct("ParseHashMap").field("keys", &keys).finish()
+    }
+}
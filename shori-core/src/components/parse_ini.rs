@@ -0,0 +1,274 @@
+use std::fmt::Write as _;
+use std::marker::PhantomData;
+
+use crate::components::text_tree::{self, Naming, Node};
+use crate::{FieldInfo, RecordError, Traced};
+
+const NAMING: Naming = Naming {
+    noun: "key",
+    separator: ".",
+    uppercase: false,
+};
+
+#[derive(Debug)]
+/// An INI document serialized from a `T`, which can be decoded back into it.
+///
+/// Scalar fields are written as `key = value` in the global section, in
+/// declaration order and named by their serde key; nested structs and maps become
+/// `[sections]`, deeper ones `[dotted.sections]`. Sequences of scalars are
+/// comma-separated and `None` fields are left out.
+pub struct ParseIni<T>(String, PhantomData<fn() -> T>);
+
+impl<T> ParseIni<T> {
+    pub(crate) fn new(value: String) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: &T, fields: &'static [FieldInfo]) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        let encode = |error: crate::BoxError| crate::Error::encode(crate::Format::Ini, error);
+        let value = serde_json::to_value(value).map_err(|error| encode(error.into()))?;
+        let serde_json::Value::Object(object) = value else {
+            return Err(encode("only structs and maps can be written as INI".into()));
+        };
+        // Struct fields are written in declaration order; a field serde did
+        // not write could not be read back.
+        let entries: Vec<_> = if fields.is_empty() {
+            object
+                .iter()
+                .map(|(name, value)| (name.as_str(), value))
+                .collect()
+        } else {
+            fields
+                .iter()
+                .map(|field| match object.get(field.serde_name) {
+                    Some(value) => Ok((field.serde_name, value)),
+                    None => Err(encode("the field was not serialized".into()).at(field.serde_name)),
+                })
+                .collect::<crate::Result<_>>()?
+        };
+        let mut document = String::new();
+        write_section(&mut document, "", entries)?;
+        Ok(Self::new(document))
+    }
+}
+
+impl<T: Traced> ParseIni<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns the INI document.
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseIni` and returns the INI document.
+    pub fn string(self) -> String {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseIni` and wraps the INI document in `Arc<String>`.
+    ///
+    /// Enables shared ownership across threads.
+    pub fn arc(self) -> std::sync::Arc<String> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseIni` and wraps the INI document in `tokio::sync::Mutex`.
+    ///
+    /// Useful in async environments for interior mutability.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<String> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseIni` and wraps the INI document in `std::sync::Mutex`.
+    ///
+    /// Enables interior mutability in synchronous code.
+    pub fn mutex(self) -> std::sync::Mutex<String> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseIni` and wraps the INI document in `RefCell`.
+    ///
+    /// Allows mutable borrows at runtime in single-threaded contexts.
+    pub fn ref_cell(self) -> std::cell::RefCell<String> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseIni` and wraps the INI document in `UnsafeCell`.
+    ///
+    /// Low-level container for interior mutability.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<String> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseIni` and initializes a `OnceCell` with the INI document.
+    pub fn once_cell(self) -> std::cell::OnceCell<String> {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the INI document into the original struct.
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn from(&self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        decode(&self.0)
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the given INI document into the original struct.
+    ///
+    /// Values are parsed into the type of their field, so `age = 42` fills
+    /// a `u32`.
+    ///
+    /// # Errors
+    /// Returns an error listing every malformed line and every missing or
+    /// invalid key.
+    pub fn from_str(&self, document: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        decode(document)
+    }
+}
+
+/// Deserializes an INI document keyed by serde keys, collecting every
+/// malformed line and invalid key.
+pub(crate) fn decode<T>(document: &str) -> crate::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut root = Node::Map(Vec::new());
+    let mut errors = Vec::new();
+    let mut section: Vec<&str> = Vec::new();
+    for (line, text) in (1u64..).zip(document.lines()) {
+        let text = text.trim();
+        if text.is_empty() || text.starts_with(';') || text.starts_with('#') {
+            continue;
+        }
+        if let Some(header) = text.strip_prefix('[') {
+            match header.strip_suffix(']') {
+                Some(name) => section = name.split('.').map(str::trim).collect(),
+                None => errors.push(RecordError::new(
+                    Some(line),
+                    None,
+                    "unclosed section header",
+                )),
+            }
+            continue;
+        }
+        let Some((key, value)) = text.split_once('=') else {
+            errors.push(RecordError::new(Some(line), None, "expected `key = value`"));
+            continue;
+        };
+        let mut path = section.clone();
+        path.push(key.trim());
+        if let Err(error) = root.insert(&path, unquote(value.trim()), NAMING.noun) {
+            errors.push(RecordError::new(Some(line), Some(&path.join(".")), error));
+        }
+    }
+    text_tree::deserialize(&root, "", NAMING, crate::Format::Ini, errors)
+}
+
+/// Writes the scalar entries of a section, then its nested sections.
+fn write_section(
+    document: &mut String,
+    section: &str,
+    entries: Vec<(&str, &serde_json::Value)>,
+) -> crate::Result<()> {
+    let encode = |error: crate::BoxError| crate::Error::encode(crate::Format::Ini, error);
+    if !section.is_empty() {
+        if !document.is_empty() {
+            document.push('\n');
+        }
+        let _ = writeln!(document, "[{section}]");
+    }
+    let mut nested = Vec::new();
+    for (key, value) in entries {
+        let path = if section.is_empty() {
+            key.to_string()
+        } else {
+            format!("{section}.{key}")
+        };
+        if key.is_empty() || key.contains(['=', '[', ']', '.', '\n', '\r']) {
+            return Err(encode("the name cannot be written as an INI key".into()).at(&path));
+        }
+        match value {
+            serde_json::Value::Null => {}
+            serde_json::Value::Object(object) => nested.push((path, object)),
+            value => {
+                let value = scalar(value).map_err(|error| encode(error.into()).at(&path))?;
+                let _ = writeln!(document, "{key} = {value}");
+            }
+        }
+    }
+    for (path, object) in nested {
+        let entries = object
+            .iter()
+            .map(|(name, value)| (name.as_str(), value))
+            .collect();
+        write_section(document, &path, entries)?;
+    }
+    Ok(())
+}
+
+/// Formats a value of a `key = value` line; strings with surrounding
+/// whitespace or quotes are quoted.
+fn scalar(value: &serde_json::Value) -> Result<String, &'static str> {
+    match value {
+        serde_json::Value::String(text) if text.contains(['\n', '\r']) => {
+            Err("multi-line strings cannot be written as INI")
+        }
+        serde_json::Value::String(text) if text.trim() != text || text.starts_with('"') => {
+            Ok(format!("\"{text}\""))
+        }
+        serde_json::Value::String(text) => Ok(text.clone()),
+        serde_json::Value::Array(items) => {
+            let items = items
+                .iter()
+                .map(|item| match item {
+                    serde_json::Value::String(text)
+                        if text.contains(',') || text.trim() != text || text.starts_with('"') =>
+                    {
+                        Err("this string cannot be written in an INI list")
+                    }
+                    serde_json::Value::Array(_)
+                    | serde_json::Value::Object(_)
+                    | serde_json::Value::Null => Err("only lists of scalars can be written as INI"),
+                    item => scalar(item),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(items.join(", "))
+        }
+        serde_json::Value::Bool(flag) => Ok(flag.to_string()),
+        serde_json::Value::Number(number) => Ok(number.to_string()),
+        serde_json::Value::Null | serde_json::Value::Object(_) => Err("not a scalar value"),
+    }
+}
+
+/// Removes one pair of double quotes around a value.
+fn unquote(value: &str) -> &str {
+    value
+        .strip_prefix('"')
+        .and_then(|inner| inner.strip_suffix('"'))
+        .unwrap_or(value)
+}
@@ -0,0 +1,105 @@
+use std::marker::PhantomData;
+
+use crate::Traced;
+
+#[derive(Debug)]
+/// A `serde_json::Value` serialized from a `T`, which can be decoded back into it.
+pub struct ParseJson<T>(serde_json::Value, PhantomData<fn() -> T>);
+
+impl<T> ParseJson<T> {
+    pub(crate) fn new(value: serde_json::Value) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        serde_json::to_value(value)
+            .map(Self::new)
+            .map_err(|error| crate::Error::encode(crate::Format::Json, error))
+    }
+}
+
+impl<T: Traced> ParseJson<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns a reference to the internal `serde_json::Value`.
+    pub fn get(&self) -> &serde_json::Value {
+        &self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `Arc` wrapped `serde_json::Value`
+    /// for thread-safe shared ownership.
+    pub fn arc(self) -> std::sync::Arc<serde_json::Value> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a Tokio async mutex wrapping the JSON value.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<serde_json::Value> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a standard mutex wrapping the JSON value.
+    pub fn mutex(self) -> std::sync::Mutex<serde_json::Value> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a `RefCell` wrapping the JSON value,
+    /// allowing interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<serde_json::Value> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `UnsafeCell` wrapping the JSON value.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<serde_json::Value> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a `OnceCell` wrapping the JSON value,
+    /// allowing one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<serde_json::Value> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and attempts to deserialize the JSON value into
+    /// the original struct type. Returns a `Result` with the deserialized struct or an error.
+    ///
+    /// # Errors
+    /// Returns an error if the value does not match `T`.
+    pub fn from(self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_value(self.0)
+            .map_err(|error| crate::Error::decode(crate::Format::Json, error))
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize a given `serde_json::Value` reference into
+    /// the original struct type. Returns a `Result` with the deserialized struct or an error.
+    ///
+    /// # Errors
+    /// Returns an error if the value does not match `T`.
+    pub fn from_value(&self, value: &serde_json::Value) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_json::from_value(value.clone())
+            .map_err(|error| crate::Error::decode(crate::Format::Json, error))
+    }
+}
@@ -0,0 +1,103 @@
+use std::io::{BufRead, BufReader, Read, Write};
+use std::marker::PhantomData;
+
+use crate::{ParseVec, RecordError, Traced};
+
+impl<T: Traced> ParseVec<T> {
+    /// Writes the elements as JSON Lines: one compact JSON document per
+    /// element, each followed by a newline.
+    ///
+    /// Elements are serialized straight into `writer`, without building a
+    /// `serde_json::Value`; wrap files and sockets in a `BufWriter`.
+    ///
+    /// # Errors
+    /// Returns an error if an element cannot be serialized or writing fails.
+    pub fn jsonl<W: Write>(&self, writer: W) -> crate::Result<()>
+    where
+        T: crate::Metadata<Capabilities: crate::capabilities::Jsonl>,
+        T: serde::Serialize,
+    {
+        let encode = |error: crate::BoxError| crate::Error::encode(crate::Format::Json, error);
+        let mut writer = writer;
+        for element in self.as_inner() {
+            serde_json::to_writer(&mut writer, element).map_err(|error| encode(error.into()))?;
+            writer
+                .write_all(b"\n")
+                .map_err(|error| encode(error.into()))?;
+        }
+        writer.flush().map_err(|error| encode(error.into()))
+    }
+}
+
+/// Iterator over the values of a JSON Lines input, returned by
+/// [`Parsable::jsonl_reader`](crate::Parsable::jsonl_reader).
+///
+/// Values are decoded one line at a time; blank lines are skipped. A line
+/// that fails yields an [`Error::Records`](crate::Error::Records) error
+/// carrying its line number, and iteration can continue past it. A failed
+/// read is yielded the same way but ends the iteration, since the reader
+/// cannot be trusted to make progress.
+pub struct JsonLines<T, R> {
+    reader: BufReader<R>,
+    line: String,
+    number: u64,
+    done: bool,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T, R: Read> JsonLines<T, R> {
+    pub(crate) fn new(reader: R) -> Self {
+        Self {
+            reader: BufReader::new(reader),
+            line: String::new(),
+            number: 0,
+            done: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of lines read so far.
+    pub fn line_number(&self) -> u64 {
+        self.number
+    }
+}
+
+impl<T: serde::de::DeserializeOwned, R: Read> Iterator for JsonLines<T, R> {
+    type Item = crate::Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while !self.done {
+            self.line.clear();
+            let read = self.reader.read_line(&mut self.line);
+            if let Ok(0) = read {
+                self.done = true;
+                return None;
+            }
+            self.number += 1;
+            let error = match read {
+                Ok(_) if self.line.trim().is_empty() => continue,
+                Ok(_) => match serde_json::from_str(&self.line) {
+                    Ok(value) => return Some(Ok(value)),
+                    Err(error) => RecordError::new(Some(self.number), None, error),
+                },
+                Err(error) => {
+                    self.done = true;
+                    RecordError::new(Some(self.number), None, error)
+                }
+            };
+            return Some(Err(crate::Error::decode_records(
+                crate::Format::Json,
+                vec![error],
+            )));
+        }
+        None
+    }
+}
+
+impl<T, R> std::fmt::Debug for JsonLines<T, R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JsonLines")
+            .field("line_number", &self.number)
+            .finish()
+    }
+}
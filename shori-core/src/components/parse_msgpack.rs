@@ -0,0 +1,136 @@
+use std::marker::PhantomData;
+
+use crate::Traced;
+
+#[derive(Debug)]
+/// MessagePack bytes encoded from a `T`, which can be decoded back into it.
+///
+/// Structs are written either as maps keyed by field name (`msgpack()`) or
+/// as positional arrays (`msgpack_compact()`); decoding accepts both.
+pub struct ParseMsgPack<T>(Vec<u8>, PhantomData<fn() -> T>);
+
+impl<T> ParseMsgPack<T> {
+    pub(crate) fn new(value: Vec<u8>) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        rmp_serde::to_vec_named(value)
+            .map(Self::new)
+            .map_err(|error| crate::Error::encode(crate::Format::MsgPack, error))
+    }
+
+    pub(crate) fn encode_compact(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        rmp_serde::to_vec(value)
+            .map(Self::new)
+            .map_err(|error| crate::Error::encode(crate::Format::MsgPack, error))
+    }
+}
+
+impl<T: Traced> ParseMsgPack<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns a reference to the inner byte slice.
+    pub fn get(&self) -> &[u8] {
+        &self.0
+    }
+
+    #[cfg(feature = "hex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns a hexadecimal string representation of the bytes.
+    pub fn hex(&self) -> String {
+        hex::encode(&self.0)
+    }
+
+    #[cfg(feature = "hex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode an instance of the struct from a hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex string is invalid or if decoding fails.
+    pub fn from_hex(&self, hex_str: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let bytes = hex::decode(hex_str)
+            .map_err(|error| crate::Error::decode(crate::Format::Hex, error))?;
+        self.from_bytes(&bytes)
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the inner bytes into an `Arc<Vec<u8>>`.
+    pub fn arc(self) -> std::sync::Arc<Vec<u8>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a Tokio `Mutex`.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<Vec<u8>> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a standard `Mutex`.
+    pub fn mutex(self) -> std::sync::Mutex<Vec<u8>> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a `RefCell` for interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<Vec<u8>> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in an `UnsafeCell`.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<Vec<u8>> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a `OnceCell` for one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<Vec<u8>> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode the struct instance from the inner byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns a decoding error if the byte slice cannot be decoded.
+    pub fn from(&self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.from_bytes(&self.0)
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode the struct instance from the provided byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns a decoding error if the byte slice cannot be decoded.
+    pub fn from_bytes(&self, bytes: &[u8]) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        rmp_serde::from_slice(bytes)
+            .map_err(|error| crate::Error::decode(crate::Format::MsgPack, error))
+    }
+}
@@ -0,0 +1,62 @@
+use crate::Traced;
+
+#[derive(Debug)]
+/// A wrapper around `std::sync::Mutex<T>` with container conversion helpers.
+pub struct ParseMutex<T>(std::sync::Mutex<T>);
+
+impl<T> ParseMutex<T> {
+    pub(crate) fn new(value: std::sync::Mutex<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the inner `Mutex`.
+    pub fn as_inner(&self) -> &std::sync::Mutex<T> {
+        &self.0
+    }
+}
+
+impl<T: Traced> ParseMutex<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns the inner `Mutex` containing the struct.
+    pub fn get(self) -> std::sync::Mutex<T> {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `Arc` wrapping the `Mutex` for shared thread-safe ownership.
+    pub fn arc(self) -> std::sync::Arc<std::sync::Mutex<T>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a boxed `Mutex`.
+    pub fn boxed(self) -> Box<std::sync::Mutex<T>> {
+        Box::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a `RefCell` wrapping the `Mutex`.
+    pub fn ref_cell(self) -> std::cell::RefCell<std::sync::Mutex<T>> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `UnsafeCell` wrapping the `Mutex`.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<std::sync::Mutex<T>> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a `OnceCell` wrapping the `Mutex`.
+    /// The `OnceCell` is initialized with the `Mutex` value.
+    pub fn once_cell(self) -> std::cell::OnceCell<std::sync::Mutex<T>> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+}
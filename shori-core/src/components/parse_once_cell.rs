@@ -0,0 +1,38 @@
+use crate::Traced;
+
+#[derive(Debug)]
+/// A wrapper around `std::cell::OnceCell<T>` with container conversion helpers.
+pub struct ParseOnceCell<T>(std::cell::OnceCell<T>);
+
+impl<T> ParseOnceCell<T> {
+    pub(crate) fn new(value: std::cell::OnceCell<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the inner `OnceCell`.
+    pub fn as_inner(&self) -> &std::cell::OnceCell<T> {
+        &self.0
+    }
+}
+
+impl<T: Traced> ParseOnceCell<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns an `Option` with a reference to the contained value if it has been initialized.
+    pub fn get(&self) -> Option<&T> {
+        self.0.get()
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `Arc` wrapping the `OnceCell` for thread-safe shared ownership.
+    pub fn arc(self) -> std::sync::Arc<std::cell::OnceCell<T>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a boxed `OnceCell`.
+    pub fn boxed(self) -> Box<std::cell::OnceCell<T>> {
+        Box::new(self.0)
+    }
+}
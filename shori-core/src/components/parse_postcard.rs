@@ -0,0 +1,156 @@
+use std::marker::PhantomData;
+
+use crate::Traced;
+
+#[derive(Debug)]
+/// `postcard` bytes encoded from a `T`, which can be decoded back into it.
+///
+/// The bytes are either plain or COBS-framed (`postcard_cobs()`): framed
+/// messages contain no zero byte but the trailing delimiter, so they can be
+/// split out of a serial stream.
+pub struct ParsePostcard<T> {
+    bytes: Vec<u8>,
+    cobs: bool,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> ParsePostcard<T> {
+    pub(crate) fn new(bytes: Vec<u8>, cobs: bool) -> Self {
+        Self {
+            bytes,
+            cobs,
+            marker: PhantomData,
+        }
+    }
+
+    pub(crate) fn encode(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        postcard::to_stdvec(value)
+            .map(|bytes| Self::new(bytes, false))
+            .map_err(|error| crate::Error::encode(crate::Format::Postcard, error))
+    }
+
+    pub(crate) fn encode_cobs(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        postcard::to_stdvec_cobs(value)
+            .map(|bytes| Self::new(bytes, true))
+            .map_err(|error| crate::Error::encode(crate::Format::Postcard, error))
+    }
+}
+
+impl<T: Traced> ParsePostcard<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns a reference to the inner byte slice.
+    pub fn get(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Returns `true` if the bytes are COBS-framed.
+    pub fn is_cobs(&self) -> bool {
+        self.cobs
+    }
+
+    #[cfg(feature = "hex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns a hexadecimal string representation of the bytes.
+    pub fn hex(&self) -> String {
+        hex::encode(&self.bytes)
+    }
+
+    #[cfg(feature = "hex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode an instance of the struct from a hex string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex string is invalid or if decoding fails.
+    pub fn from_hex(&self, hex_str: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let bytes = hex::decode(hex_str)
+            .map_err(|error| crate::Error::decode(crate::Format::Hex, error))?;
+        self.from_bytes(&bytes)
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the inner bytes into an `Arc<Vec<u8>>`.
+    pub fn arc(self) -> std::sync::Arc<Vec<u8>> {
+        std::sync::Arc::new(self.bytes)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a Tokio `Mutex`.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<Vec<u8>> {
+        tokio::sync::Mutex::new(self.bytes)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a standard `Mutex`.
+    pub fn mutex(self) -> std::sync::Mutex<Vec<u8>> {
+        std::sync::Mutex::new(self.bytes)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a `RefCell` for interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<Vec<u8>> {
+        std::cell::RefCell::new(self.bytes)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in an `UnsafeCell`.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<Vec<u8>> {
+        std::cell::UnsafeCell::new(self.bytes)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the inner bytes in a `OnceCell` for one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<Vec<u8>> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.bytes).ok();
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode the struct instance from the inner byte slice.
+    ///
+    /// # Errors
+    ///
+    /// Returns a decoding error if the byte slice cannot be decoded.
+    pub fn from(&self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.from_bytes(&self.bytes)
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to decode the struct instance from the provided byte slice,
+    /// which must use the same framing as `self`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a decoding error if the byte slice cannot be decoded.
+    pub fn from_bytes(&self, bytes: &[u8]) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let result = if self.cobs {
+            // COBS frames are decoded in place.
+            postcard::from_bytes_cobs(&mut bytes.to_vec())
+        } else {
+            postcard::from_bytes(bytes)
+        };
+        result.map_err(|error| crate::Error::decode(crate::Format::Postcard, error))
+    }
+}
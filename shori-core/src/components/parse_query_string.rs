@@ -0,0 +1,378 @@
+use std::marker::PhantomData;
+
+use serde::de::value::{Error as ValueError, MapDeserializer, SeqDeserializer};
+use serde::de::{Error as _, IntoDeserializer, Visitor};
+
+use crate::{FieldInfo, Traced};
+
+#[derive(Debug)]
+/// A URL query string (`application/x-www-form-urlencoded`) serialized from
+/// a `T`, which can be decoded back into it.
+///
+/// Fields are keyed by the key serde writes them under; nested structs and
+/// maps use brackets (`db[host]=x`), sequences of scalars repeat their key
+/// (`tag=a&tag=b`) and other sequences are indexed (`items[0][name]=x`).
+/// Empty sequences are written as an empty value (`tag=`), so a sequence
+/// holding a single empty string reads back as empty. `None` fields are left
+/// out, and an empty value reads back as `None`, so `Some(String::new())`
+/// reads back as `None` as well.
+pub struct ParseQueryString<T>(String, PhantomData<fn() -> T>);
+
+impl<T> ParseQueryString<T> {
+    pub(crate) fn new(value: String) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: &T, fields: &'static [FieldInfo]) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        let encode =
+            |error: crate::BoxError| crate::Error::encode(crate::Format::QueryString, error);
+        let value = serde_json::to_value(value).map_err(|error| encode(error.into()))?;
+        let serde_json::Value::Object(object) = value else {
+            return Err(encode(
+                "only structs and maps can be written as a query string".into(),
+            ));
+        };
+        let mut pairs = Vec::new();
+        if fields.is_empty() {
+            for (name, value) in &object {
+                flatten(&mut pairs, encode_component(name), value);
+            }
+        }
+        // Struct fields are written in declaration order; a field serde did
+        // not write could not be read back.
+        for field in fields {
+            let value = object.get(field.serde_name).ok_or_else(|| {
+                encode("the field was not serialized".into()).at(field.serde_name)
+            })?;
+            flatten(&mut pairs, encode_component(field.serde_name), value);
+        }
+        Ok(Self::new(pairs.join("&")))
+    }
+}
+
+impl<T: Traced> ParseQueryString<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns the query string.
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseQueryString` and returns the query string.
+    pub fn string(self) -> String {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseQueryString` and wraps the query string in `Arc<String>`.
+    ///
+    /// Enables shared ownership across threads.
+    pub fn arc(self) -> std::sync::Arc<String> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseQueryString` and wraps the query string in `tokio::sync::Mutex`.
+    ///
+    /// Useful in async environments for interior mutability.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<String> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseQueryString` and wraps the query string in `std::sync::Mutex`.
+    ///
+    /// Enables interior mutability in synchronous code.
+    pub fn mutex(self) -> std::sync::Mutex<String> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseQueryString` and wraps the query string in `RefCell`.
+    ///
+    /// Allows mutable borrows at runtime in single-threaded contexts.
+    pub fn ref_cell(self) -> std::cell::RefCell<String> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseQueryString` and wraps the query string in `UnsafeCell`.
+    ///
+    /// Low-level container for interior mutability.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<String> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseQueryString` and initializes a `OnceCell` with the query string.
+    pub fn once_cell(self) -> std::cell::OnceCell<String> {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the query string into the original struct.
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn from(&self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        decode(&self.0)
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the given query string into the original struct.
+    ///
+    /// Values are parsed into the type of their field, so `age=42` fills a
+    /// `u32`; a leading `?` is ignored.
+    ///
+    /// # Errors
+    /// Returns an error if a key conflicts with another one or a value does
+    /// not parse into its field type.
+    pub fn from_str(&self, query: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        decode(query)
+    }
+}
+
+/// Deserializes a query string keyed by serde keys into a `T`.
+pub(crate) fn decode<T>(query: &str) -> crate::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let decode = |error: crate::BoxError| crate::Error::decode(crate::Format::QueryString, error);
+    let query = query.strip_prefix('?').unwrap_or(query);
+    let mut root = Node::Map(Vec::new());
+    for (key, value) in form_urlencoded::parse(query.as_bytes()) {
+        root.insert(&split_key(&key), value.into_owned())
+            .map_err(|error| decode(error.into()).at(&key))?;
+    }
+    T::deserialize(root).map_err(|error| decode(error.into()))
+}
+
+/// Appends the `key=value` pairs of `value` under the already encoded `key`.
+fn flatten(pairs: &mut Vec<String>, key: String, value: &serde_json::Value) {
+    match value {
+        serde_json::Value::Null => {}
+        serde_json::Value::Bool(flag) => pairs.push(format!("{key}={flag}")),
+        serde_json::Value::Number(number) => pairs.push(format!("{key}={number}")),
+        serde_json::Value::String(text) => pairs.push(format!("{key}={}", encode_component(text))),
+        serde_json::Value::Object(object) => {
+            for (name, value) in object {
+                flatten(pairs, format!("{key}[{}]", encode_component(name)), value);
+            }
+        }
+        serde_json::Value::Array(items) if items.is_empty() => pairs.push(format!("{key}=")),
+        serde_json::Value::Array(items) => {
+            let scalars = items
+                .iter()
+                .all(|item| !item.is_array() && !item.is_object());
+            for (index, item) in items.iter().enumerate() {
+                let key = if scalars {
+                    key.clone()
+                } else {
+                    format!("{key}[{index}]")
+                };
+                flatten(pairs, key, item);
+            }
+        }
+    }
+}
+
+fn encode_component(text: &str) -> String {
+    form_urlencoded::byte_serialize(text.as_bytes()).collect()
+}
+
+/// Splits `a[b][c]` into `["a", "b", "c"]`; malformed brackets are kept as
+/// part of the name.
+fn split_key(key: &str) -> Vec<String> {
+    let Some(open) = key.find('[') else {
+        return vec![key.to_string()];
+    };
+    let mut path = vec![key[..open].to_string()];
+    let mut rest = &key[open..];
+    while let Some(inner) = rest.strip_prefix('[') {
+        let Some(close) = inner.find(']') else {
+            return vec![key.to_string()];
+        };
+        path.push(inner[..close].to_string());
+        rest = &inner[close + 1..];
+    }
+    if rest.is_empty() {
+        path
+    } else {
+        vec![key.to_string()]
+    }
+}
+
+/// Decoded query string: text values, repeated keys and bracketed keys.
+#[derive(Debug)]
+enum Node {
+    Text(String),
+    List(Vec<Node>),
+    Map(Vec<(String, Node)>),
+}
+
+impl Node {
+    fn insert(&mut self, path: &[String], value: String) -> Result<(), String> {
+        let Some((first, rest)) = path.split_first() else {
+            return match self {
+                Node::Text(text) => {
+                    let previous = std::mem::take(text);
+                    *self = Node::List(vec![Node::Text(previous), Node::Text(value)]);
+                    Ok(())
+                }
+                Node::List(items) => {
+                    items.push(Node::Text(value));
+                    Ok(())
+                }
+                Node::Map(_) => Err("a value conflicts with nested keys".to_string()),
+            };
+        };
+        let Node::Map(entries) = self else {
+            return Err("nested keys conflict with a value".to_string());
+        };
+        match entries.iter_mut().find(|(name, _)| name == first) {
+            Some((_, node)) => node.insert(rest, value),
+            None if rest.is_empty() => {
+                entries.push((first.clone(), Node::Text(value)));
+                Ok(())
+            }
+            None => {
+                let mut node = Node::Map(Vec::new());
+                node.insert(rest, value)?;
+                entries.push((first.clone(), node));
+                Ok(())
+            }
+        }
+    }
+
+    /// Elements of the node read as a sequence: none for an empty value, a
+    /// single value, repeated values or indexed keys in index order.
+    fn into_items(self) -> Vec<Node> {
+        match self {
+            Node::Text(text) if text.is_empty() => Vec::new(),
+            Node::Text(text) => vec![Node::Text(text)],
+            Node::List(items) => items,
+            Node::Map(mut entries) => {
+                entries.sort_by_key(|(index, _)| index.parse::<usize>().unwrap_or(usize::MAX));
+                entries.into_iter().map(|(_, node)| node).collect()
+            }
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+                match self {
+                    Node::Text(text) => visitor.$visit(text.parse().map_err(ValueError::custom)?),
+                    node => node.deserialize_any(visitor),
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> serde::Deserializer<'de> for Node {
+    type Error = ValueError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            Node::Text(text) => visitor.visit_string(text),
+            Node::List(items) => visitor.visit_seq(SeqDeserializer::new(items.into_iter())),
+            Node::Map(entries) => visitor.visit_map(MapDeserializer::new(entries.into_iter())),
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            Node::Text(text) if text.is_empty() => visitor.visit_none(),
+            node => visitor.visit_some(node),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        match self {
+            Node::Text(text) if text.is_empty() => visitor.visit_unit(),
+            node => node.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, ValueError> {
+        visitor.visit_seq(SeqDeserializer::new(self.into_items().into_iter()))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, ValueError> {
+        match self {
+            Node::Text(text) => visitor.visit_enum(text.into_deserializer()),
+            node => node.deserialize_any(visitor),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct tuple_struct map struct identifier
+        ignored_any
+    }
+}
+
+impl IntoDeserializer<'_, ValueError> for Node {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
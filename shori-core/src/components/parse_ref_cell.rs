@@ -0,0 +1,45 @@
+use crate::Traced;
+
+#[derive(Debug)]
+/// A wrapper around `std::cell::RefCell<T>` with container conversion helpers.
+pub struct ParseRefCell<T>(std::cell::RefCell<T>);
+
+impl<T> ParseRefCell<T> {
+    pub(crate) fn new(value: std::cell::RefCell<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the inner `RefCell`.
+    pub fn as_inner(&self) -> &std::cell::RefCell<T> {
+        &self.0
+    }
+}
+
+impl<T: Traced> ParseRefCell<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns the inner `RefCell` containing the struct.
+    pub fn get(self) -> std::cell::RefCell<T> {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `Arc` wrapping the `RefCell` for shared ownership.
+    pub fn arc(self) -> std::sync::Arc<std::cell::RefCell<T>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a boxed `RefCell`.
+    pub fn boxed(self) -> Box<std::cell::RefCell<T>> {
+        Box::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `UnsafeCell` wrapping the `RefCell`.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<std::cell::RefCell<T>> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+}
@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+
+use crate::Traced;
+
+#[derive(Debug)]
+/// A RON document serialized from a `T`, which can be decoded back into it.
+///
+/// The document is kept as text: unlike `toml::Value`, it keeps enum
+/// variants, tuples and unit values exactly as `T` serialized them.
+pub struct ParseRon<T>(String, PhantomData<fn() -> T>);
+
+impl<T> ParseRon<T> {
+    pub(crate) fn new(value: String) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        ron::to_string(value)
+            .map(Self::new)
+            .map_err(|error| crate::Error::encode(crate::Format::Ron, error))
+    }
+
+    pub(crate) fn encode_pretty(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+            .map(Self::new)
+            .map_err(|error| crate::Error::encode(crate::Format::Ron, error))
+    }
+}
+
+impl<T: Traced> ParseRon<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns the RON document.
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseRon` and returns the RON document.
+    pub fn string(self) -> String {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseRon` and wraps the document in `Arc<String>`.
+    ///
+    /// Enables shared ownership across threads.
+    pub fn arc(self) -> std::sync::Arc<String> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseRon` and wraps the document in `tokio::sync::Mutex`.
+    ///
+    /// Useful in async environments for interior mutability.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<String> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseRon` and wraps the document in `std::sync::Mutex`.
+    ///
+    /// Enables interior mutability in synchronous code.
+    pub fn mutex(self) -> std::sync::Mutex<String> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseRon` and wraps the document in `RefCell`.
+    ///
+    /// Allows mutable borrows at runtime in single-threaded contexts.
+    pub fn ref_cell(self) -> std::cell::RefCell<String> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseRon` and wraps the document in `UnsafeCell`.
+    ///
+    /// Low-level container for interior mutability.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<String> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseRon` and initializes a `OnceCell` with the document.
+    pub fn once_cell(self) -> std::cell::OnceCell<String> {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the RON document into the original struct.
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn from(self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.from_str(&self.0)
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the given RON document into the original struct.
+    ///
+    /// # Errors
+    /// Returns an error if the document is not valid RON or does not match `T`.
+    pub fn from_str(&self, document: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        ron::from_str(document).map_err(|error| crate::Error::decode(crate::Format::Ron, error))
+    }
+}
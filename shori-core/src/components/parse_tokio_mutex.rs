@@ -0,0 +1,61 @@
+use crate::Traced;
+
+#[derive(Debug)]
+/// A wrapper around `tokio::sync::Mutex<T>` providing utility methods.
+pub struct ParseTokioMutex<T>(tokio::sync::Mutex<T>);
+
+impl<T> ParseTokioMutex<T> {
+    pub(crate) fn new(value: tokio::sync::Mutex<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the inner `tokio::sync::Mutex`.
+    pub fn as_inner(&self) -> &tokio::sync::Mutex<T> {
+        &self.0
+    }
+}
+
+impl<T: Traced> ParseTokioMutex<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `self` and returns the inner `tokio::sync::Mutex`.
+    pub fn get(self) -> tokio::sync::Mutex<T> {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the mutex in an `Arc` for shared ownership across threads.
+    pub fn arc(self) -> std::sync::Arc<tokio::sync::Mutex<T>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Boxes the mutex into a `Box`.
+    pub fn boxed(self) -> Box<tokio::sync::Mutex<T>> {
+        Box::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the mutex in a `RefCell` for interior mutability in single-threaded contexts.
+    pub fn ref_cell(self) -> std::cell::RefCell<tokio::sync::Mutex<T>> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the mutex in an `UnsafeCell` for low-level interior mutability.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<tokio::sync::Mutex<T>> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Places the mutex in a `OnceCell`, allowing single initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<tokio::sync::Mutex<T>> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+}
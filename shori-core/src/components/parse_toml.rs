@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+
+use crate::Traced;
+
+#[derive(Debug)]
+/// A `toml::Value` serialized from a `T`, which can be decoded back into it.
+pub struct ParseToml<T>(toml::Value, PhantomData<fn() -> T>);
+
+impl<T> ParseToml<T> {
+    pub(crate) fn new(value: toml::Value) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: &T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        let encode = |error: crate::BoxError| crate::Error::encode(crate::Format::Toml, error);
+        let toml_string = toml::to_string(value).map_err(|error| encode(error.into()))?;
+        let value =
+            toml::from_str::<toml::Value>(&toml_string).map_err(|error| encode(error.into()))?;
+        Ok(Self::new(value))
+    }
+}
+
+impl<T: Traced> ParseToml<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns an immutable reference to the internal `toml::Value`.
+    ///
+    /// Useful when you want to inspect or manipulate the raw value.
+    pub fn get(&self) -> &toml::Value {
+        &self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseToml` and wraps it in `Arc<toml::Value>`.
+    ///
+    /// Enables shared ownership across threads.
+    pub fn arc(self) -> std::sync::Arc<toml::Value> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseToml` and wraps it in `tokio::sync::Mutex`.
+    ///
+    /// Useful in async environments for interior mutability.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<toml::Value> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseToml` and wraps it in `std::sync::Mutex`.
+    ///
+    /// Enables interior mutability in synchronous code.
+    pub fn mutex(self) -> std::sync::Mutex<toml::Value> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseToml` and wraps it in `RefCell`.
+    ///
+    /// Allows mutable borrows at runtime in single-threaded contexts.
+    pub fn ref_cell(self) -> std::cell::RefCell<toml::Value> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseToml` and wraps it in `UnsafeCell`.
+    ///
+    /// Low-level container for interior mutability.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<toml::Value> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseToml` and initializes a `OnceCell` with the TOML value.
+    ///
+    /// Returns the populated `OnceCell`. If already set internally, silently does nothing.
+    pub fn once_cell(self) -> std::cell::OnceCell<toml::Value> {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the internal `toml::Value` into the original struct.
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn from(self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.0
+            .try_into()
+            .map_err(|error| crate::Error::decode(crate::Format::Toml, error))
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the given `toml::Value` into the original struct.
+    ///
+    /// This method borrows the `value` rather than consuming `self`.
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn from_value(&self, value: &toml::Value) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        value
+            .clone()
+            .try_into()
+            .map_err(|error| crate::Error::decode(crate::Format::Toml, error))
+    }
+}
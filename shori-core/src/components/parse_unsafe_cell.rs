@@ -0,0 +1,38 @@
+use crate::Traced;
+
+#[derive(Debug)]
+/// A wrapper around `std::cell::UnsafeCell<T>` with container conversion helpers.
+pub struct ParseUnsafeCell<T>(std::cell::UnsafeCell<T>);
+
+impl<T> ParseUnsafeCell<T> {
+    pub(crate) fn new(value: std::cell::UnsafeCell<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the inner `UnsafeCell`.
+    pub fn as_inner(&self) -> &std::cell::UnsafeCell<T> {
+        &self.0
+    }
+}
+
+impl<T: Traced> ParseUnsafeCell<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `self` and returns the inner `UnsafeCell`.
+    pub fn get(self) -> std::cell::UnsafeCell<T> {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Wraps the `UnsafeCell` in an `Arc` for shared ownership across threads.
+    pub fn arc(self) -> std::sync::Arc<std::cell::UnsafeCell<T>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Boxes the `UnsafeCell`.
+    pub fn boxed(self) -> Box<std::cell::UnsafeCell<T>> {
+        Box::new(self.0)
+    }
+}
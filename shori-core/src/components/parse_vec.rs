@@ -0,0 +1,68 @@
+use crate::Traced;
+
+#[derive(Debug)]
+/// A wrapper around a `Vec<T>` with conversion methods for common wrappers.
+pub struct ParseVec<T>(Vec<T>);
+
+impl<T> ParseVec<T> {
+    pub(crate) fn new(value: Vec<T>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the inner `Vec`.
+    pub fn as_inner(&self) -> &Vec<T> {
+        &self.0
+    }
+}
+
+impl<T> From<Vec<T>> for ParseVec<T> {
+    /// Wraps an existing `Vec`, e.g. to write several values at once.
+    fn from(value: Vec<T>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<T: Traced> ParseVec<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `self` and returns the internal `Vec`.
+    pub fn get(self) -> Vec<T> {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the `Vec` into an `Arc` for shared ownership.
+    pub fn arc(self) -> std::sync::Arc<Vec<T>> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "box")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the `Vec` into a boxed version for heap allocation.
+    pub fn boxed(self) -> Box<Vec<T>> {
+        Box::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the `Vec` into a `RefCell` for interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<Vec<T>> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the `Vec` into an `UnsafeCell` for unchecked interior mutability.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<Vec<T>> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Converts the `Vec` into a `OnceCell` that can be set once.
+    pub fn once_cell(self) -> std::cell::OnceCell<Vec<T>> {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        cell
+    }
+}
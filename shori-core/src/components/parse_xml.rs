@@ -0,0 +1,291 @@
+use std::marker::PhantomData;
+
+use quick_xml::Writer;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::reader::Reader;
+
+use crate::{FieldInfo, Traced};
+
+#[derive(Debug)]
+/// An XML document serialized from a `T`, which can be decoded back into it.
+///
+/// Fields become child elements of the root element, named by the key serde
+/// writes them under (`#[serde(rename)]`, `#[serde(rename_all)]`);
+/// `#[parser(xml_attribute)]` fields become attributes of the root element,
+/// left out when they are `None` or empty; an empty attribute reads as an
+/// absent one, so non-`Option` attribute fields that may be empty need
+/// `#[serde(default)]`.
+pub struct ParseXml<T>(String, &'static [FieldInfo], PhantomData<fn() -> T>);
+
+impl<T> ParseXml<T> {
+    pub(crate) fn new(value: String, fields: &'static [FieldInfo]) -> Self {
+        Self(value, fields, PhantomData)
+    }
+
+    pub(crate) fn encode(value: &T, root: &str, fields: &'static [FieldInfo]) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        let encode = |error: crate::BoxError| crate::Error::encode(crate::Format::Xml, error);
+        let document = quick_xml::se::to_string_with_root(root, value)
+            .map_err(|error| encode(error.into()))?;
+        let document = to_attributes(&document, fields)?;
+        Ok(Self::new(document, fields))
+    }
+}
+
+impl<T: Traced> ParseXml<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns the XML document.
+    pub fn get(&self) -> &str {
+        &self.0
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseXml` and returns the XML document.
+    pub fn string(self) -> String {
+        self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseXml` and wraps the document in `Arc<String>`.
+    pub fn arc(self) -> std::sync::Arc<String> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseXml` and wraps the document in `tokio::sync::Mutex`.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<String> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseXml` and wraps the document in `std::sync::Mutex`.
+    pub fn mutex(self) -> std::sync::Mutex<String> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseXml` and wraps the document in `RefCell`.
+    pub fn ref_cell(self) -> std::cell::RefCell<String> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseXml` and wraps the document in `UnsafeCell`.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<String> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes `ParseXml` and initializes a `OnceCell` with the document.
+    pub fn once_cell(self) -> std::cell::OnceCell<String> {
+        let cell = std::cell::OnceCell::new();
+        let _ = cell.set(self.0);
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the XML document into the original struct.
+    ///
+    /// # Errors
+    /// Returns an error if deserialization fails.
+    pub fn from(&self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.from_str(&self.0)
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize the given XML document into the original struct.
+    ///
+    /// The name of the root element is not checked.
+    ///
+    /// # Errors
+    /// Returns an error if the document is not well-formed or does not match `T`.
+    pub fn from_str(&self, document: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        decode(document, self.1)
+    }
+}
+
+/// Deserializes a document written with the attribute fields of `fields`
+/// back into a `T`.
+pub(crate) fn decode<T>(document: &str, fields: &'static [FieldInfo]) -> crate::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let document = from_attributes(document, fields)?;
+    quick_xml::de::from_str(&document)
+        .map_err(|error| crate::Error::decode(crate::Format::Xml, error))
+}
+
+/// Rewrites a document produced by serde, which writes every field as a
+/// child element named by its serde key, moving the attribute fields of
+/// `fields` onto the root element.
+fn to_attributes(document: &str, fields: &'static [FieldInfo]) -> crate::Result<String> {
+    let encode = |error: crate::BoxError| crate::Error::encode(crate::Format::Xml, error);
+    let mut reader = Reader::from_str(document);
+    let mut root: Option<BytesStart<'static>> = None;
+    // Events after the root start tag, written once every attribute is known.
+    let mut children: Vec<Event<'static>> = Vec::new();
+    // Attribute field whose element is being read, with its text so far.
+    let mut attribute: Option<(&FieldInfo, String)> = None;
+    let mut depth = 0usize;
+    loop {
+        let event = reader.read_event().map_err(|error| encode(error.into()))?;
+        match (event, root.as_mut()) {
+            (Event::Eof, _) => break,
+            (Event::Start(start), None) => {
+                root = Some(start.into_owned());
+                depth = 1;
+            }
+            (Event::Start(start), Some(_))
+                if depth == 1 && attribute_field(fields, start.name().as_ref()).is_some() =>
+            {
+                depth = 2;
+                attribute =
+                    attribute_field(fields, start.name().as_ref()).map(|f| (f, String::new()));
+            }
+            // An empty element is how serde writes `None` (and empty
+            // strings): the attribute is left out.
+            (Event::Empty(start), Some(_))
+                if depth == 1 && attribute_field(fields, start.name().as_ref()).is_some() => {}
+            (Event::End(_), Some(root)) if depth == 2 && attribute.is_some() => {
+                depth = 1;
+                if let Some((field, value)) = attribute.take() {
+                    set_attribute(root, field, &value)?;
+                }
+            }
+            (Event::Text(text), Some(_)) if attribute.is_some() => {
+                if let Some((_, value)) = attribute.as_mut() {
+                    value.push_str(&text.unescape().map_err(|error| encode(error.into()))?);
+                }
+            }
+            (_, Some(_)) if attribute.is_some() => {
+                let key = attribute.map_or("", |(field, _)| field.serde_name);
+                return Err(encode("an attribute must be a scalar value".into()).at(key));
+            }
+            (event, _) => {
+                match &event {
+                    Event::Start(_) => depth += 1,
+                    Event::End(_) => depth -= 1,
+                    _ => {}
+                }
+                children.push(event.into_owned());
+            }
+        }
+    }
+
+    let mut writer = Writer::new(Vec::new());
+    let events = root.map(Event::Start).into_iter().chain(children);
+    for event in events {
+        writer
+            .write_event(event)
+            .map_err(|error| encode(error.into()))?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|error| encode(error.into()))
+}
+
+/// Rewrites a document with attribute fields on its root element into the
+/// shape serde expects: one child element per field.
+fn from_attributes(document: &str, fields: &'static [FieldInfo]) -> crate::Result<String> {
+    let decode = |error: crate::BoxError| crate::Error::decode(crate::Format::Xml, error);
+    let mut reader = Reader::from_str(document);
+    let mut events: Vec<Event<'static>> = Vec::new();
+    let mut depth = 0usize;
+    loop {
+        let event = reader.read_event().map_err(|error| decode(error.into()))?;
+        let is_empty = matches!(event, Event::Empty(_));
+        match event {
+            Event::Eof => break,
+            Event::Start(start) | Event::Empty(start) if depth == 0 => {
+                // An empty root may still carry attribute fields, so it is
+                // always written as a start and an end tag.
+                let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+                let mut root = BytesStart::new(name.clone());
+                let mut values = Vec::new();
+                for attr in start.attributes() {
+                    let attr = attr.map_err(|error| decode(error.into()))?;
+                    match attribute_field(fields, attr.key.as_ref()) {
+                        Some(field) => {
+                            let value = attr
+                                .unescape_value()
+                                .map_err(|error| decode(error.into()))?;
+                            // An empty attribute reads as an absent one.
+                            if !value.is_empty() {
+                                values.push((field.serde_name, value.into_owned()));
+                            }
+                        }
+                        None => root.push_attribute(attr),
+                    }
+                }
+                events.push(Event::Start(root));
+                for (name, value) in values {
+                    events.push(Event::Start(BytesStart::new(name)));
+                    events.push(Event::Text(BytesText::new(&value).into_owned()));
+                    events.push(Event::End(BytesEnd::new(name)));
+                }
+                if is_empty {
+                    events.push(Event::End(BytesEnd::new(name)));
+                } else {
+                    depth = 1;
+                }
+            }
+            event => {
+                match &event {
+                    Event::Start(_) => depth += 1,
+                    Event::End(_) => depth = depth.saturating_sub(1),
+                    _ => {}
+                }
+                events.push(event.into_owned());
+            }
+        }
+    }
+
+    let mut writer = Writer::new(Vec::new());
+    for event in events {
+        writer
+            .write_event(event)
+            .map_err(|error| decode(error.into()))?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|error| decode(error.into()))
+}
+
+/// Finds the `#[parser(xml_attribute)]` field serde writes under `name`.
+fn attribute_field(fields: &'static [FieldInfo], name: &[u8]) -> Option<&'static FieldInfo> {
+    fields
+        .iter()
+        .find(|field| field.xml_attribute && field.serde_name.as_bytes() == name)
+}
+
+/// Adds the value of an attribute field to the root element; a field written
+/// more than once (a sequence) cannot be an attribute.
+fn set_attribute(
+    root: &mut BytesStart<'static>,
+    field: &FieldInfo,
+    value: &str,
+) -> crate::Result<()> {
+    if root
+        .try_get_attribute(field.serde_name)
+        .map_err(|error| crate::Error::encode(crate::Format::Xml, error))?
+        .is_some()
+    {
+        return Err(crate::Error::encode(
+            crate::Format::Xml,
+            "an attribute must be a scalar value",
+        )
+        .at(field.serde_name));
+    }
+    root.push_attribute((field.serde_name, value));
+    Ok(())
+}
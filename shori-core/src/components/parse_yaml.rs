@@ -0,0 +1,127 @@
+use std::marker::PhantomData;
+
+use crate::Traced;
+
+#[derive(Debug)]
+/// A `serde_yaml::Value` serialized from a `T`, which can be decoded back into it.
+pub struct ParseYaml<T>(serde_yaml::Value, PhantomData<fn() -> T>);
+
+impl<T> ParseYaml<T> {
+    pub(crate) fn new(value: serde_yaml::Value) -> Self {
+        Self(value, PhantomData)
+    }
+
+    pub(crate) fn encode(value: T) -> crate::Result<Self>
+    where
+        T: serde::Serialize,
+    {
+        serde_yaml::to_value(value)
+            .map(Self::new)
+            .map_err(|error| crate::Error::encode(crate::Format::Yaml, error))
+    }
+}
+
+impl<T: Traced> ParseYaml<T> {
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Returns a reference to the internal `serde_yaml::Value`.
+    pub fn get(&self) -> &serde_yaml::Value {
+        &self.0
+    }
+
+    #[cfg(feature = "arc")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `Arc` wrapped `serde_yaml::Value`
+    /// for thread-safe shared ownership.
+    pub fn arc(self) -> std::sync::Arc<serde_yaml::Value> {
+        std::sync::Arc::new(self.0)
+    }
+
+    #[cfg(feature = "tokio")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a Tokio async mutex wrapping the YAML value.
+    pub fn tokio_mutex(self) -> tokio::sync::Mutex<serde_yaml::Value> {
+        tokio::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "mutex")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a standard mutex wrapping the YAML value.
+    pub fn mutex(self) -> std::sync::Mutex<serde_yaml::Value> {
+        std::sync::Mutex::new(self.0)
+    }
+
+    #[cfg(feature = "refcell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a `RefCell` wrapping the YAML value,
+    /// allowing interior mutability.
+    pub fn ref_cell(self) -> std::cell::RefCell<serde_yaml::Value> {
+        std::cell::RefCell::new(self.0)
+    }
+
+    #[cfg(feature = "unsafecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns an `UnsafeCell` wrapping the YAML value.
+    pub fn unsafe_cell(self) -> std::cell::UnsafeCell<serde_yaml::Value> {
+        std::cell::UnsafeCell::new(self.0)
+    }
+
+    #[cfg(feature = "oncecell")]
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and returns a `OnceCell` wrapping the YAML value,
+    /// allowing one-time initialization.
+    pub fn once_cell(self) -> std::cell::OnceCell<serde_yaml::Value> {
+        let cell = std::cell::OnceCell::new();
+        cell.set(self.0).ok();
+        cell
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Renders the YAML value as a YAML document.
+    ///
+    /// # Errors
+    /// Returns an error if the value cannot be emitted as YAML.
+    pub fn string(&self) -> crate::Result<String> {
+        serde_yaml::to_string(&self.0)
+            .map_err(|error| crate::Error::encode(crate::Format::Yaml, error))
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Consumes self and attempts to deserialize the YAML value into
+    /// the original struct type. Returns a `Result` with the deserialized struct or an error.
+    ///
+    /// # Errors
+    /// Returns an error if the value does not match `T`.
+    pub fn from(self) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_yaml::from_value(self.0)
+            .map_err(|error| crate::Error::decode(crate::Format::Yaml, error))
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize a given `serde_yaml::Value` reference into
+    /// the original struct type. Returns a `Result` with the deserialized struct or an error.
+    ///
+    /// # Errors
+    /// Returns an error if the value does not match `T`.
+    pub fn from_value(&self, value: &serde_yaml::Value) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_yaml::from_value(value.clone())
+            .map_err(|error| crate::Error::decode(crate::Format::Yaml, error))
+    }
+
+    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
+    /// Attempts to deserialize a YAML document into the original struct type.
+    ///
+    /// # Errors
+    /// Returns an error if the document is not valid YAML or does not match `T`.
+    pub fn from_str(&self, yaml: &str) -> crate::Result<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        serde_yaml::from_str(yaml).map_err(|error| crate::Error::decode(crate::Format::Yaml, error))
+    }
+}
@@ -0,0 +1,85 @@
+pub use super::capabilities;
+
+pub use super::error::*;
+
+pub use super::field_wrapper::*;
+
+pub use super::parse::*;
+
+pub use super::parsable::*;
+
+pub use super::traced::*;
+
+pub use super::traits::*;
+
+#[cfg(feature = "arc")]
+pub use super::parse_arc::*;
+
+#[cfg(feature = "box")]
+pub use super::parse_box::*;
+
+#[cfg(feature = "hashmap")]
+pub use super::parse_hash_map::*;
+
+#[cfg(feature = "mutex")]
+pub use super::parse_mutex::*;
+
+#[cfg(feature = "oncecell")]
+pub use super::parse_once_cell::*;
+
+#[cfg(feature = "refcell")]
+pub use super::parse_ref_cell::*;
+
+#[cfg(feature = "unsafecell")]
+pub use super::parse_unsafe_cell::*;
+
+#[cfg(feature = "vec")]
+pub use super::parse_vec::*;
+
+#[cfg(feature = "serde_json")]
+pub use super::parse_json::*;
+
+#[cfg(feature = "tokio")]
+pub use super::parse_tokio_mutex::*;
+
+#[cfg(feature = "toml")]
+pub use super::parse_toml::*;
+
+#[cfg(feature = "bincode")]
+pub use super::parse_bin::*;
+
+#[cfg(feature = "argon2")]
+pub use super::argon2_hash::*;
+
+#[cfg(feature = "serde_yaml")]
+pub use super::parse_yaml::*;
+
+#[cfg(feature = "msgpack")]
+pub use super::parse_msgpack::*;
+
+#[cfg(feature = "cbor")]
+pub use super::parse_cbor::*;
+
+#[cfg(feature = "ron")]
+pub use super::parse_ron::*;
+
+#[cfg(feature = "csv")]
+pub use super::parse_csv::*;
+
+#[cfg(feature = "xml")]
+pub use super::parse_xml::*;
+
+#[cfg(feature = "postcard")]
+pub use super::parse_postcard::*;
+
+#[cfg(all(feature = "serde_json", feature = "vec"))]
+pub use super::parse_jsonl::*;
+
+#[cfg(feature = "query_string")]
+pub use super::parse_query_string::*;
+
+#[cfg(feature = "ini")]
+pub use super::parse_ini::*;
+
+#[cfg(feature = "bson")]
+pub use super::parse_bson::*;
@@ -0,0 +1,598 @@
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::{DeserializeSeed, Error as _, IntoDeserializer, MapAccess, SeqAccess, Visitor};
+
+use crate::RecordError;
+
+/// Text values nested under string keys, such as environment variables
+/// split on `__` or INI sections.
+#[derive(Debug)]
+pub(crate) enum Node {
+    Text(String),
+    Map(Vec<(String, Node)>),
+}
+
+impl Node {
+    /// Adds `value` under `path`; keys are compared ignoring case.
+    pub(crate) fn insert(&mut self, path: &[&str], value: &str, noun: &str) -> Result<(), String> {
+        let Node::Map(entries) = self else {
+            return Err(format!("nested {noun}s conflict with a value"));
+        };
+        let Some((first, rest)) = path.split_first() else {
+            return Err(format!("a value conflicts with nested {noun}s"));
+        };
+        let existing = entries
+            .iter_mut()
+            .find(|(segment, _)| segment.eq_ignore_ascii_case(first));
+        match existing {
+            Some((_, Node::Text(_))) if rest.is_empty() => {
+                Err(format!("the {noun} is set more than once, ignoring case"))
+            }
+            Some((_, node)) => node.insert(rest, value, noun),
+            None if rest.is_empty() => {
+                entries.push((first.to_string(), Node::Text(value.to_string())));
+                Ok(())
+            }
+            None => {
+                let mut node = Node::Map(Vec::new());
+                node.insert(rest, value, noun)?;
+                entries.push((first.to_string(), node));
+                Ok(())
+            }
+        }
+    }
+
+    fn source(&self) -> Source<'_> {
+        match self {
+            Node::Text(text) => Source::Text(text),
+            Node::Map(entries) => Source::Map(entries),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Source<'a> {
+    Text(&'a str),
+    Map(&'a [(String, Node)]),
+    Missing,
+}
+
+/// How the keys of nested values are named in errors.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Naming {
+    /// What a key is called, e.g. "variable".
+    pub(crate) noun: &'static str,
+    /// Joins a key to the nested key below it, e.g. `__`.
+    pub(crate) separator: &'static str,
+    /// Whether keys are written in upper case.
+    pub(crate) uppercase: bool,
+}
+
+/// Deserializes `root` into a `T`, parsing every text value into the type
+/// requested with `FromStr`; keys match the names serde reads, ignoring
+/// case, and `prefix` starts every full key.
+///
+/// A strict pass is tried first; if it fails, lenient passes fill invalid
+/// and missing fields with placeholders so that every failing key is
+/// reported at once, after the `errors` already found. Only the fields serde
+/// asks for through `missing_field` are missing: each lenient pass that
+/// finds a new one is run again with a placeholder for it, so fields with
+/// `#[serde(default)]` or of type `Option` are never reported.
+pub(crate) fn deserialize<T>(
+    root: &Node,
+    prefix: &str,
+    naming: Naming,
+    format: crate::Format,
+    mut errors: Vec<RecordError>,
+) -> crate::Result<T>
+where
+    T: serde::de::DeserializeOwned,
+{
+    if errors.is_empty()
+        && let Ok(value) = T::deserialize(Entry::root(root, prefix, naming, None))
+    {
+        return Ok(value);
+    }
+    let missing = RefCell::new(HashMap::new());
+    let mut passes = 0;
+    let (result, collected) = loop {
+        let lenient = Lenient {
+            errors: RefCell::new(Vec::new()),
+            missing: &missing,
+            found: RefCell::new(false),
+        };
+        let result = T::deserialize(Entry::root(root, prefix, naming, Some(&lenient)));
+        passes += 1;
+        if !lenient.found.into_inner() || passes == MAX_PASSES {
+            break (result, lenient.errors.into_inner());
+        }
+    };
+    errors.extend(collected);
+    match result {
+        Ok(value) if errors.is_empty() => Ok(value),
+        Ok(_) => Err(crate::Error::decode_records(format, errors)),
+        Err(error) => {
+            // Once a key has been reported, later failures are most likely
+            // caused by its placeholder.
+            if errors.is_empty() {
+                errors.push(RecordError::new(None, None, error));
+            }
+            Err(crate::Error::decode_records(format, errors))
+        }
+    }
+}
+
+/// Upper bound on the lenient passes; each one finds at least one new
+/// missing key, so this is only reached with deeply nested missing structs.
+const MAX_PASSES: usize = 64;
+
+/// Error of a pass, telling apart the fields serde found missing.
+#[derive(Debug)]
+enum TreeError {
+    Custom(String),
+    MissingField(&'static str),
+}
+
+impl Display for TreeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TreeError::Custom(message) => f.write_str(message),
+            TreeError::MissingField(field) => write!(f, "missing field `{field}`"),
+        }
+    }
+}
+
+impl std::error::Error for TreeError {}
+
+impl serde::de::Error for TreeError {
+    fn custom<T: Display>(message: T) -> Self {
+        TreeError::Custom(message.to_string())
+    }
+
+    fn missing_field(field: &'static str) -> Self {
+        TreeError::MissingField(field)
+    }
+}
+
+/// State of a lenient pass.
+struct Lenient<'a> {
+    /// Errors reported by the pass.
+    errors: RefCell<Vec<RecordError>>,
+    /// Full keys serde found missing, with the field name serde asked for,
+    /// kept across passes.
+    missing: &'a RefCell<HashMap<String, &'static str>>,
+    /// Whether the pass found a missing key not known before.
+    found: RefCell<bool>,
+}
+
+/// A value being deserialized, with the full key it comes from.
+struct Entry<'a> {
+    source: Source<'a>,
+    /// The full key, or the prefix for the root.
+    var: String,
+    naming: Naming,
+    /// Whether this is the root, whose keys are not preceded by a separator.
+    root: bool,
+    /// State of the lenient pass; `None` in the strict pass.
+    lenient: Option<&'a Lenient<'a>>,
+}
+
+impl<'a> Entry<'a> {
+    fn root(
+        node: &'a Node,
+        prefix: &str,
+        naming: Naming,
+        lenient: Option<&'a Lenient<'a>>,
+    ) -> Self {
+        Self {
+            source: node.source(),
+            var: prefix.to_string(),
+            naming,
+            root: true,
+            lenient,
+        }
+    }
+
+    fn child(&self, segment: &str, source: Source<'a>) -> Self {
+        let var = if self.root {
+            format!("{}{segment}", self.var)
+        } else {
+            format!("{}{}{segment}", self.var, self.naming.separator)
+        };
+        Self {
+            source,
+            var,
+            naming: self.naming,
+            root: false,
+            lenient: self.lenient,
+        }
+    }
+
+    fn item(&self, text: &'a str) -> Self {
+        Self {
+            source: Source::Text(text),
+            var: self.var.clone(),
+            naming: self.naming,
+            root: false,
+            lenient: self.lenient,
+        }
+    }
+
+    fn text(&self) -> Result<&'a str, String> {
+        match self.source {
+            Source::Text(text) => Ok(text),
+            Source::Map(_) => Err(format!(
+                "expected a value, found nested {}s `{}{}*`",
+                self.naming.noun, self.var, self.naming.separator
+            )),
+            Source::Missing => Err(self.missing()),
+        }
+    }
+
+    fn missing(&self) -> String {
+        format!("missing {}", self.naming.noun)
+    }
+
+    fn nested(&self) -> String {
+        format!(
+            "expected nested {}s `{}{}*`, found a value",
+            self.naming.noun, self.var, self.naming.separator
+        )
+    }
+
+    fn parsed<T>(&self) -> Result<T, String>
+    where
+        T: FromStr<Err: Display>,
+    {
+        self.text()?
+            .parse()
+            .map_err(|error: T::Err| error.to_string())
+    }
+
+    /// Records `message` against the key in the lenient pass, or fails
+    /// with it in the strict one.
+    fn report(&self, message: String) -> Result<(), TreeError> {
+        match self.lenient {
+            Some(lenient) => {
+                let error = RecordError::new(None, Some(&self.var), message);
+                lenient.errors.borrow_mut().push(error);
+                Ok(())
+            }
+            None => Err(TreeError::Custom(message)),
+        }
+    }
+
+    /// Reports a failure that has no placeholder value.
+    fn fail<T>(&self, message: String) -> Result<T, TreeError> {
+        self.report(message)?;
+        Err(TreeError::Custom(format!("invalid `{}`", self.var)))
+    }
+
+    /// Matches a key segment to the name serde expects, ignoring case.
+    fn field_name(segment: &str, names: &[&'static str]) -> String {
+        names
+            .iter()
+            .copied()
+            .find(|name| name.eq_ignore_ascii_case(segment))
+            .map_or_else(|| segment.to_lowercase(), str::to_string)
+    }
+
+    /// The segment naming the key of a field.
+    fn field_segment(&self, name: &str) -> String {
+        if self.naming.uppercase {
+            name.to_uppercase()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Gives serde a placeholder for every field of this value an earlier
+    /// lenient pass found missing, which reports itself when read.
+    fn add_missing(&self, entries: &mut Vec<(String, Entry<'a>)>) {
+        let Some(lenient) = self.lenient else {
+            return;
+        };
+        for (var, name) in lenient.missing.borrow().iter() {
+            let child = self.child(&self.field_segment(name), Source::Missing);
+            if child.var == *var && !entries.iter().any(|(entry, _)| entry == name) {
+                entries.push((name.to_string(), child));
+            }
+        }
+    }
+
+    /// Visits the fields of this value; in the lenient pass, a field serde
+    /// finds missing is remembered so that the next pass gives a
+    /// placeholder for it.
+    fn visit_fields<'de, V: Visitor<'de>>(
+        &self,
+        visitor: V,
+        entries: Vec<(String, Entry<'a>)>,
+    ) -> Result<V::Value, TreeError> {
+        match (visitor.visit_map(Fields::new(entries)), self.lenient) {
+            (Err(TreeError::MissingField(name)), Some(lenient)) => {
+                let child = self.child(&self.field_segment(name), Source::Missing);
+                let known = lenient
+                    .missing
+                    .borrow_mut()
+                    .insert(child.var.clone(), name)
+                    .is_some();
+                if known {
+                    // The placeholder did not reach serde, so it is reported here.
+                    child.report(child.missing())?;
+                } else {
+                    *lenient.found.borrow_mut() = true;
+                }
+                Err(TreeError::Custom(format!("incomplete `{}`", self.var)))
+            }
+            (result, _) => result,
+        }
+    }
+
+    fn children(&self) -> &'a [(String, Node)] {
+        match self.source {
+            Source::Map(entries) => entries,
+            Source::Text(_) | Source::Missing => &[],
+        }
+    }
+}
+
+macro_rules! deserialize_parsed {
+    ($($method:ident => $visit:ident,)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+                match self.parsed() {
+                    Ok(value) => visitor.$visit(value),
+                    Err(message) => {
+                        self.report(message)?;
+                        visitor.$visit(Default::default())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl<'de> serde::Deserializer<'de> for Entry<'_> {
+    type Error = TreeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+        match self.source {
+            Source::Text(text) => visitor.visit_str(text),
+            Source::Map(_) => self.deserialize_map(visitor),
+            Source::Missing => {
+                self.report(self.missing())?;
+                visitor.visit_unit()
+            }
+        }
+    }
+
+    deserialize_parsed! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+        match self.text() {
+            Ok(text) => visitor.visit_str(text),
+            Err(message) => {
+                self.report(message)?;
+                visitor.visit_str("")
+            }
+        }
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+        self.deserialize_str(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+        match self.source {
+            Source::Missing | Source::Text("") => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+        visitor.visit_unit()
+    }
+
+    /// Sequences are comma-separated (`a,b`) or nested under the indexes
+    /// `0`, `1`, ...
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+        let items = match self.source {
+            Source::Text("") => Vec::new(),
+            Source::Text(text) => text.split(',').map(|item| self.item(item.trim())).collect(),
+            Source::Map(entries) => {
+                let mut entries: Vec<_> = entries.iter().collect();
+                entries.sort_by_key(|(index, _)| index.parse::<usize>().unwrap_or(usize::MAX));
+                entries
+                    .into_iter()
+                    .map(|(index, node)| self.child(index, node.source()))
+                    .collect()
+            }
+            Source::Missing => {
+                self.report(self.missing())?;
+                Vec::new()
+            }
+        };
+        visitor.visit_seq(Items(items.into_iter()))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(
+        self,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TreeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _len: usize,
+        visitor: V,
+    ) -> Result<V::Value, TreeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, TreeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, TreeError> {
+        match self.source {
+            Source::Text(_) => self.fail(self.nested()),
+            Source::Map(entries) => {
+                // Upper-case keys name lower-case fields, as in structs.
+                let mut entries: Vec<_> = entries
+                    .iter()
+                    .map(|(segment, node)| {
+                        let key = if self.naming.uppercase {
+                            segment.to_lowercase()
+                        } else {
+                            segment.clone()
+                        };
+                        (key, self.child(segment, node.source()))
+                    })
+                    .collect();
+                // Maps stand for structs with `#[serde(flatten)]` fields too.
+                self.add_missing(&mut entries);
+                self.visit_fields(visitor, entries)
+            }
+            Source::Missing => {
+                self.report(self.missing())?;
+                let mut entries = Vec::new();
+                self.add_missing(&mut entries);
+                self.visit_fields(visitor, entries)
+            }
+        }
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        names: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TreeError> {
+        if let Source::Text(_) = self.source {
+            return self.fail(self.nested());
+        }
+        let mut entries: Vec<(String, Entry<'_>)> = self
+            .children()
+            .iter()
+            .map(|(segment, node)| {
+                let name = Self::field_name(segment, names);
+                (name, self.child(segment, node.source()))
+            })
+            .collect();
+        self.add_missing(&mut entries);
+        // Declaration order, so that errors are reported in that order too.
+        entries.sort_by_key(|(entry, _)| {
+            names
+                .iter()
+                .position(|name| name == entry)
+                .unwrap_or(usize::MAX)
+        });
+        self.visit_fields(visitor, entries)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _name: &'static str,
+        _variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, TreeError> {
+        let text = match self.text() {
+            Ok(text) => text,
+            Err(message) => return self.fail(message),
+        };
+        let variant: serde::de::value::StrDeserializer<'_, TreeError> = text.into_deserializer();
+        match visitor.visit_enum(variant) {
+            Ok(value) => Ok(value),
+            Err(error) => self.fail(error.to_string()),
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        bytes byte_buf unit_struct identifier ignored_any
+    }
+}
+
+struct Items<'a>(std::vec::IntoIter<Entry<'a>>);
+
+impl<'de> SeqAccess<'de> for Items<'_> {
+    type Error = TreeError;
+
+    fn next_element_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, TreeError> {
+        self.0
+            .next()
+            .map(|entry| seed.deserialize(entry))
+            .transpose()
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.0.len())
+    }
+}
+
+struct Fields<'a> {
+    entries: std::vec::IntoIter<(String, Entry<'a>)>,
+    value: Option<Entry<'a>>,
+}
+
+impl<'a> Fields<'a> {
+    fn new(entries: Vec<(String, Entry<'a>)>) -> Self {
+        Self {
+            entries: entries.into_iter(),
+            value: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for Fields<'_> {
+    type Error = TreeError;
+
+    fn next_key_seed<S: DeserializeSeed<'de>>(
+        &mut self,
+        seed: S,
+    ) -> Result<Option<S::Value>, TreeError> {
+        let Some((name, entry)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.value = Some(entry);
+        seed.deserialize(name.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, TreeError> {
+        match self.value.take() {
+            Some(entry) => seed.deserialize(entry),
+            None => Err(TreeError::custom("value requested before its key")),
+        }
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.entries.len())
+    }
+}
@@ -0,0 +1,19 @@
+/// Bound put on the wrapped types of every traced method.
+///
+/// With the `nekotracing` feature, traced methods format their receiver and
+/// return value, so this is `Debug`; without it, every type implements it.
+#[cfg(feature = "nekotracing")]
+pub trait Traced: std::fmt::Debug {}
+
+#[cfg(feature = "nekotracing")]
+impl<T: std::fmt::Debug + ?Sized> Traced for T {}
+
+/// Bound put on the wrapped types of every traced method.
+///
+/// With the `nekotracing` feature, traced methods format their receiver and
+/// return value, so this is `Debug`; without it, every type implements it.
+#[cfg(not(feature = "nekotracing"))]
+pub trait Traced {}
+
+#[cfg(not(feature = "nekotracing"))]
+impl<T: ?Sized> Traced for T {}
@@ -0,0 +1,93 @@
+use crate::Parse;
+
+/// Metadata of a single field, implemented by `#[derive(Parser)]` on the
+/// zero-sized tag type it emits for every field.
+pub trait FieldTag {
+    /// Name of the field in the Rust source, or its position (`"0"`, `"1"`,
+    /// ...) for tuple struct fields.
+    const NAME: &'static str;
+    /// Key used for the field in generated outputs, honoring
+    /// `#[parser(rename = "...")]`.
+    const KEY: &'static str;
+    /// Key serde serializes the field under, see [`FieldInfo::serde_name`].
+    const SERDE_NAME: &'static str;
+    /// Type implementing the [`capabilities`](crate::capabilities) enabled
+    /// on the struct the field belongs to.
+    type Capabilities;
+}
+
+/// Field access for structs, implemented by `#[derive(Parser)]`.
+pub trait Fields: Sized {
+    /// Per-type selector with one method per field, returned by
+    /// [`Parse::field`].
+    type Selector;
+    /// Tuple of references to every field that is not `#[parser(skip)]`,
+    /// returned by [`Parse::tuple`].
+    type Tuple<'shori>
+    where
+        Self: 'shori;
+
+    /// Wraps `parse` into the field selector.
+    fn selector(parse: Parse<Self>) -> Self::Selector;
+
+    /// Borrows every field that is not skipped, in declaration order.
+    fn tuple(&self) -> Self::Tuple<'_>;
+}
+
+/// Conversion of a struct into a map of its fields, implemented by
+/// `#[derive(Parser)]` when every field type that is not `#[parser(skip)]` is
+/// `Send + Sync + 'static`.
+pub trait IntoHashMap {
+    /// Moves every field that is not skipped into a map keyed by field key,
+    /// honoring `#[parser(rename = "...")]`.
+    fn into_hashmap(
+        self,
+    ) -> std::collections::HashMap<String, Box<dyn std::any::Any + Send + Sync>>;
+}
+
+/// Variant reflection for enums, implemented by `#[derive(Parser)]`.
+pub trait Variants: Sized {
+    /// Names of all the variants of the enum, in declaration order.
+    const VARIANTS: &'static [&'static str];
+    /// Per-type selector with `as_<variant>()` / `is_<variant>()` methods,
+    /// returned by [`Parse::variant`].
+    type Selector;
+
+    /// Wraps `parse` into the variant selector.
+    fn selector(parse: Parse<Self>) -> Self::Selector;
+
+    /// Returns the name of the variant held by `self`.
+    fn variant_name(&self) -> &'static str;
+}
+
+/// Static description of a single field, listed by [`Metadata::FIELDS`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldInfo {
+    /// Name of the field in the Rust source, or its position for tuple
+    /// struct fields.
+    pub name: &'static str,
+    /// Key used for the field by `hashmap()`, the field wrappers and other
+    /// reflection outputs, honoring `#[parser(rename = "...")]`.
+    pub key: &'static str,
+    /// Key serde serializes the field under, honoring `#[serde(rename)]` and
+    /// the container's `#[serde(rename_all)]`. Every serialization format,
+    /// including CSV, XML, query strings, INI and environment variables,
+    /// uses this one.
+    pub serde_name: &'static str,
+    /// Whether `xml()` writes the field as an attribute of the root element,
+    /// set by `#[parser(xml_attribute)]`.
+    pub xml_attribute: bool,
+}
+
+/// Type-level metadata, implemented by `#[derive(Parser)]` for structs and
+/// enums alike. Deriving it makes the type [`Parsable`](crate::Parsable).
+pub trait Metadata {
+    /// Name of the deriving type.
+    const NAME: &'static str;
+    /// Every field that is not `#[parser(skip)]`, in declaration order;
+    /// empty for enums.
+    const FIELDS: &'static [FieldInfo];
+    /// Type implementing the [`capabilities`](crate::capabilities) enabled
+    /// on the deriving type.
+    type Capabilities;
+}
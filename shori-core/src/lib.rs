@@ -0,0 +1,9 @@
+//! Runtime support for `#[derive(shori::Parser)]`.
+//!
+//! The derive only emits thin per-type glue (field tags, selectors and the
+//! trait impls below); every wrapper and conversion lives here once, as
+//! generic code shared by all the deriving types.
+
+mod components;
+
+pub use components::prelude::*;
@@ -0,0 +1,288 @@
+use proc_macro2::{Ident, Span};
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::{Attribute, DeriveInput, Field, LitStr, Token, Variant, Visibility};
+
+use crate::{CAPABILITIES, Diagnostics};
+
+/// Container-level options read from `#[parser(...)]` on the deriving type.
+#[derive(Default)]
+pub struct ContainerAttributes {
+    /// `#[parser(name = "Account")]`: prefix used for every generated item
+    /// instead of the type name.
+    pub name: Option<Ident>,
+    /// `#[parser(vis = "pub(crate)")]`: visibility of every generated item
+    /// instead of the visibility of the type.
+    pub vis: Option<Visibility>,
+    /// `#[parser(only(json, bin))]`: the only conversions the type enables.
+    pub only: Option<Vec<String>>,
+    /// `#[parser(exclude(unsafe_cell))]`: conversions the type disables.
+    pub exclude: Vec<String>,
+}
+
+/// Options read from `#[parser(...)]` on a single field or enum variant.
+#[derive(Default)]
+pub struct FieldAttributes {
+    /// `#[parser(skip)]`: the field or variant gets no selector method nor
+    /// per-field wrappers.
+    pub skip: bool,
+    /// `#[parser(rename = "userId")]`: key used for the field or variant by
+    /// `hashmap()`, the metadata and the field wrappers. Serialization
+    /// formats follow `#[serde(rename)]` instead.
+    pub rename: Option<LitStr>,
+    /// `#[parser(xml_attribute)]`: the field is written as an attribute of
+    /// the root element by `xml()` instead of a child element. Fields only.
+    pub xml_attribute: Option<Span>,
+}
+
+/// Parses the container-level `#[parser(...)]` attributes of `input`.
+///
+/// # Errors
+/// Returns a spanned error for every unknown or duplicated key and invalid value.
+pub fn get_container_attributes(input: &DeriveInput) -> syn::Result<ContainerAttributes> {
+    let mut attributes = ContainerAttributes::default();
+    let mut diagnostics = Diagnostics::new();
+    let mut selected_option: Option<String> = None;
+    for attr in parser_attributes(&input.attrs) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+                if attributes.name.is_some() {
+                    return Err(meta.error("duplicate `name` option"));
+                }
+                let lit: LitStr = meta.value()?.parse()?;
+                let name = lit.parse::<Ident>().map_err(|_| {
+                    syn::Error::new(lit.span(), "`name` must be a valid Rust identifier")
+                })?;
+                attributes.name = Some(name);
+                Ok(())
+            } else if meta.path.is_ident("vis") {
+                if attributes.vis.is_some() {
+                    return Err(meta.error("duplicate `vis` option"));
+                }
+                let lit: LitStr = meta.value()?.parse()?;
+                let vis = lit.parse::<Visibility>().map_err(|_| {
+                    syn::Error::new(
+                        lit.span(),
+                        "`vis` must be a valid visibility, e.g. `pub` or `pub(crate)`",
+                    )
+                })?;
+                attributes.vis = Some(vis);
+                Ok(())
+            } else if meta.path.is_ident("only") || meta.path.is_ident("exclude") {
+                let option = path_to_string(&meta.path);
+                if selected_option.is_some() {
+                    return Err(meta.error(format!(
+                        "`{}` conflicts with a previous `only` or `exclude` option",
+                        option
+                    )));
+                }
+                selected_option = Some(option.clone());
+                let capabilities = parse_capabilities(&mut diagnostics, &meta)?;
+                if option == "only" {
+                    attributes.only = Some(capabilities);
+                } else {
+                    attributes.exclude = capabilities;
+                }
+                Ok(())
+            } else {
+                unknown_option(
+                    &mut diagnostics,
+                    &meta,
+                    "container",
+                    "`name`, `vis`, `only` or `exclude`",
+                )
+            }
+        });
+        diagnostics.check(result);
+    }
+    diagnostics.finish()?;
+    Ok(attributes)
+}
+
+/// Parses the field-level `#[parser(...)]` attributes of `field`.
+///
+/// # Errors
+/// Returns a spanned error for every unknown or duplicated key, invalid value
+/// and conflicting option.
+pub fn get_field_attributes(field: &Field) -> syn::Result<FieldAttributes> {
+    get_item_attributes(&field.attrs, "field")
+}
+
+/// Parses the variant-level `#[parser(...)]` attributes of `variant`.
+///
+/// # Errors
+/// Returns a spanned error for every unknown or duplicated key, invalid value
+/// and conflicting option.
+pub fn get_variant_attributes(variant: &Variant) -> syn::Result<FieldAttributes> {
+    get_item_attributes(&variant.attrs, "variant")
+}
+
+fn get_item_attributes(attrs: &[Attribute], kind: &str) -> syn::Result<FieldAttributes> {
+    let mut attributes = FieldAttributes::default();
+    let mut diagnostics = Diagnostics::new();
+    for attr in parser_attributes(attrs) {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                if attributes.skip {
+                    return Err(meta.error("duplicate `skip` option"));
+                }
+                attributes.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                if attributes.rename.is_some() {
+                    return Err(meta.error("duplicate `rename` option"));
+                }
+                let lit: LitStr = meta.value()?.parse()?;
+                if lit.value().is_empty() {
+                    return Err(syn::Error::new(lit.span(), "`rename` must not be empty"));
+                }
+                attributes.rename = Some(lit);
+                Ok(())
+            } else if kind == "field" && meta.path.is_ident("xml_attribute") {
+                if attributes.xml_attribute.is_some() {
+                    return Err(meta.error("duplicate `xml_attribute` option"));
+                }
+                attributes.xml_attribute = Some(meta.path.span());
+                Ok(())
+            } else if kind == "field" {
+                unknown_option(
+                    &mut diagnostics,
+                    &meta,
+                    kind,
+                    "`skip`, `rename` or `xml_attribute`",
+                )
+            } else {
+                unknown_option(&mut diagnostics, &meta, kind, "`skip` or `rename`")
+            }
+        });
+        diagnostics.check(result);
+    }
+    if let (true, Some(rename)) = (attributes.skip, &attributes.rename) {
+        diagnostics.push(syn::Error::new(
+            rename.span(),
+            format!("`rename` conflicts with `skip`, a skipped {kind} has no key"),
+        ));
+    }
+    if let (true, Some(span)) = (attributes.skip, attributes.xml_attribute) {
+        diagnostics.push(syn::Error::new(
+            span,
+            "`xml_attribute` conflicts with `skip`, a skipped field is not written",
+        ));
+    }
+    diagnostics.finish()?;
+    Ok(attributes)
+}
+
+/// Parses the capability list of `only(...)` or `exclude(...)`, recording an
+/// error for every unknown or repeated capability.
+fn parse_capabilities(
+    diagnostics: &mut Diagnostics,
+    meta: &ParseNestedMeta,
+) -> syn::Result<Vec<String>> {
+    let mut capabilities = Vec::new();
+    meta.parse_nested_meta(|capability| {
+        let name = path_to_string(&capability.path);
+        if !CAPABILITIES.iter().any(|(option, _)| *option == name) {
+            let expected = CAPABILITIES
+                .iter()
+                .map(|(option, _)| format!("`{}`", option))
+                .collect::<Vec<_>>()
+                .join(", ");
+            diagnostics.push(capability.error(format!(
+                "unknown capability `{}`, expected one of {}",
+                name, expected
+            )));
+        } else if capabilities.contains(&name) {
+            diagnostics.push(capability.error(format!("duplicate capability `{}`", name)));
+        } else {
+            capabilities.push(name);
+        }
+        Ok(())
+    })?;
+    Ok(capabilities)
+}
+
+/// Reads `#[serde(rename_all = "...")]` from the deriving type, the casing
+/// serde applies to every field name.
+pub fn serde_rename_all(input: &DeriveInput) -> Option<String> {
+    serde_option(&input.attrs, "rename_all")
+}
+
+/// Reads `#[serde(rename = "...")]` from a field, the key serde writes it
+/// under.
+pub fn serde_rename(field: &Field) -> Option<String> {
+    serde_option(&field.attrs, "rename")
+}
+
+/// Returns the value of a `#[serde(...)]` option given as `option = "..."`
+/// or `option(serialize = "...")`. Other options are skipped and malformed
+/// ones ignored: serde reports its own errors.
+fn serde_option(attrs: &[Attribute], option: &str) -> Option<String> {
+    let mut value = None;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("serde")) {
+        let _ = attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident(option) {
+                return skip_value(&meta);
+            }
+            if meta.input.peek(Token![=]) {
+                let lit: LitStr = meta.value()?.parse()?;
+                value = Some(lit.value());
+                return Ok(());
+            }
+            meta.parse_nested_meta(|direction| {
+                if direction.path.is_ident("serialize") {
+                    let lit: LitStr = direction.value()?.parse()?;
+                    value = Some(lit.value());
+                    Ok(())
+                } else {
+                    skip_value(&direction)
+                }
+            })
+        });
+    }
+    value
+}
+
+/// Skips the value of an option: `= expr`, a nested list, or nothing.
+fn skip_value(meta: &ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_value(&nested))?;
+    }
+    Ok(())
+}
+
+fn parser_attributes(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path().is_ident("parser"))
+}
+
+/// Records an error for an unknown option and skips its value, so that the
+/// remaining options of the same attribute are still checked.
+fn unknown_option(
+    diagnostics: &mut Diagnostics,
+    meta: &ParseNestedMeta,
+    kind: &str,
+    expected: &str,
+) -> syn::Result<()> {
+    diagnostics.push(meta.error(format!(
+        "unknown `parser` {} option `{}`, expected {}",
+        kind,
+        path_to_string(&meta.path),
+        expected
+    )));
+    if meta.input.peek(Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|_| Ok(()))?;
+    }
+    Ok(())
+}
+
+fn path_to_string(path: &syn::Path) -> String {
+    path.segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect::<Vec<_>>()
+        .join("::")
+}
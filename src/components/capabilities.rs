@@ -0,0 +1,71 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::DeriveInput;
+
+use crate::{ContainerAttributes, generated_vis, get_container_attributes, prefixed_ident};
+
+/// Every conversion a type can select with `#[parser(only(...))]` or
+/// `#[parser(exclude(...))]`, as `(option, shori_core::capabilities trait)`.
+pub const CAPABILITIES: [(&str, &str); 25] = [
+    ("arc", "Arc"),
+    ("boxed", "Box"),
+    ("mutex", "Mutex"),
+    ("tokio_mutex", "TokioMutex"),
+    ("ref_cell", "RefCell"),
+    ("unsafe_cell", "UnsafeCell"),
+    ("once_cell", "OnceCell"),
+    ("vec", "Vec"),
+    ("hashmap", "HashMap"),
+    ("tuple", "Tuple"),
+    ("json", "Json"),
+    ("toml", "Toml"),
+    ("bin", "Bin"),
+    ("yaml", "Yaml"),
+    ("msgpack", "MsgPack"),
+    ("cbor", "Cbor"),
+    ("ron", "Ron"),
+    ("csv", "Csv"),
+    ("xml", "Xml"),
+    ("postcard", "Postcard"),
+    ("jsonl", "Jsonl"),
+    ("query_string", "QueryString"),
+    ("env", "Env"),
+    ("ini", "Ini"),
+    ("bson", "Bson"),
+];
+
+/// Generates the `{Prefix}Capabilities` marker type of `input`, implementing
+/// the `shori_core::capabilities` traits of every conversion it enables.
+///
+/// The struct and its field tags point at this type through their
+/// `Capabilities` associated type, so conversions that were not selected are
+/// rejected at compile time.
+pub fn generate_capabilities(input: &DeriveInput) -> TokenStream {
+    let attributes = get_container_attributes(input).unwrap_or_default();
+    let capabilities = prefixed_ident(input, "Capabilities");
+    let vis = generated_vis(input);
+    let markers = CAPABILITIES
+        .iter()
+        .filter(|(option, _)| is_selected(&attributes, option))
+        .map(|(_, marker)| format_ident!("{}", marker));
+
+    quote! {
+        /// Conversions enabled on the type, see `shori_core::capabilities`.
+        #[derive(Debug, Clone, Copy)]
+        #vis struct #capabilities;
+
+        #(impl ::shori_core::capabilities::#markers for #capabilities {})*
+    }
+}
+
+/// Returns whether `input` enables the conversion `option`.
+pub fn capability_selected(input: &DeriveInput, option: &str) -> bool {
+    is_selected(&get_container_attributes(input).unwrap_or_default(), option)
+}
+
+fn is_selected(attributes: &ContainerAttributes, option: &str) -> bool {
+    match &attributes.only {
+        Some(only) => only.iter().any(|selected| selected == option),
+        None => !attributes.exclude.iter().any(|excluded| excluded == option),
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use syn::spanned::Spanned;
+use syn::{Data, DeriveInput, Fields};
+
+use crate::{field_name, get_container_attributes, get_field_attributes, get_variant_attributes};
+
+/// Accumulates `syn::Error`s so that every problem found in a derive input is
+/// reported at once, each one as a `compile_error!` pointing at the offending
+/// tokens instead of a proc-macro panic pointing at the derive.
+#[derive(Default)]
+pub struct Diagnostics {
+    errors: Option<syn::Error>,
+}
+
+impl Diagnostics {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records `error`.
+    pub fn push(&mut self, error: syn::Error) {
+        match self.errors.as_mut() {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+
+    /// Records the error of `result`, if any, and returns its value otherwise.
+    pub fn check<T>(&mut self, result: syn::Result<T>) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                self.push(error);
+                None
+            }
+        }
+    }
+
+    /// Returns every recorded error combined into a single `syn::Error`.
+    ///
+    /// # Errors
+    /// Returns `Err` if at least one error was recorded.
+    pub fn finish(self) -> syn::Result<()> {
+        self.errors.map_or(Ok(()), Err)
+    }
+}
+
+/// Validates the shape and the `#[parser(...)]` attributes of `input` before
+/// any code is generated, so that the generators can assume a valid input.
+///
+/// # Errors
+/// Returns all the problems found, combined into a single `syn::Error`.
+pub fn validate_input(input: &DeriveInput) -> syn::Result<()> {
+    let mut diagnostics = Diagnostics::new();
+    diagnostics.check(get_container_attributes(input));
+
+    match &input.data {
+        Data::Struct(data) => validate_fields(&mut diagnostics, &data.fields),
+        Data::Enum(data) => {
+            let mut names = HashMap::new();
+            for variant in data.variants.iter() {
+                if let Some(attributes) = diagnostics.check(get_variant_attributes(variant)) {
+                    let name = attributes
+                        .rename
+                        .as_ref()
+                        .map_or_else(|| variant.ident.to_string(), |rename| rename.value());
+                    let span = attributes
+                        .rename
+                        .as_ref()
+                        .map_or_else(|| variant.ident.span(), |rename| rename.span());
+                    check_unique(&mut diagnostics, &mut names, name, span, "variant");
+                }
+                validate_fields(&mut diagnostics, &variant.fields);
+            }
+        }
+        Data::Union(data) => diagnostics.push(syn::Error::new(
+            data.union_token.span,
+            "`Parser` cannot be derived for unions, use a struct or an enum instead",
+        )),
+    }
+
+    diagnostics.finish()
+}
+
+fn validate_fields(diagnostics: &mut Diagnostics, fields: &Fields) {
+    let mut keys = HashMap::new();
+    for (index, field) in fields.iter().enumerate() {
+        let Some(attributes) = diagnostics.check(get_field_attributes(field)) else {
+            continue;
+        };
+        if attributes.skip {
+            continue;
+        }
+        let (key, span) = match &attributes.rename {
+            Some(rename) => (rename.value(), rename.span()),
+            None => (field_name(index, field), field.span()),
+        };
+        check_unique(diagnostics, &mut keys, key, span, "field");
+    }
+}
+
+fn check_unique(
+    diagnostics: &mut Diagnostics,
+    seen: &mut HashMap<String, proc_macro2::Span>,
+    key: String,
+    span: proc_macro2::Span,
+    kind: &str,
+) {
+    if let Some(first) = seen.get(&key) {
+        let mut error = syn::Error::new(span, format!("duplicate {kind} key `{key}`"));
+        error.combine(syn::Error::new(*first, format!("`{key}` first used here")));
+        diagnostics.push(error);
+    } else {
+        seen.insert(key, span);
+    }
+}
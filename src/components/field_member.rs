@@ -0,0 +1,69 @@
+use quote::format_ident;
+use syn::ext::IdentExt;
+use syn::{Field, Ident, Index, Member};
+
+use crate::serde_rename;
+
+/// Returns how the field at `index` is accessed on a value:
+/// `value.name` for named fields and `value.0` for tuple struct fields.
+pub fn field_member(index: usize, field: &Field) -> Member {
+    match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    }
+}
+
+/// Returns the name of the field at `index`: its identifier for named fields
+/// and its position (`"0"`, `"1"`, ...) for tuple struct fields.
+pub fn field_name(index: usize, field: &Field) -> String {
+    match &field.ident {
+        Some(ident) => ident.unraw().to_string(),
+        None => index.to_string(),
+    }
+}
+
+/// Returns the name of the selector method of the field at `index`:
+/// its identifier for named fields and `_0`, `_1`, ... for tuple struct fields.
+pub fn field_method(index: usize, field: &Field) -> Ident {
+    match &field.ident {
+        Some(ident) => ident.clone(),
+        None => format_ident!("_{}", index),
+    }
+}
+
+/// Returns the key serde serializes the field at `index` under: its
+/// `#[serde(rename)]`, or its name cased by the container's
+/// `#[serde(rename_all)]` rule. Tuple struct fields keep their position.
+pub fn serde_field_name(index: usize, field: &Field, rename_all: Option<&str>) -> String {
+    if let Some(rename) = serde_rename(field) {
+        return rename;
+    }
+    let name = field_name(index, field);
+    if field.ident.is_none() {
+        return name;
+    }
+    let pascal = || {
+        name.split('_')
+            .map(|word| {
+                let mut chars = word.chars();
+                chars.next().map_or_else(String::new, |first| {
+                    first.to_uppercase().chain(chars).collect()
+                })
+            })
+            .collect::<String>()
+    };
+    match rename_all {
+        Some("UPPERCASE" | "SCREAMING_SNAKE_CASE") => name.to_ascii_uppercase(),
+        Some("PascalCase") => pascal(),
+        Some("camelCase") => {
+            let pascal = pascal();
+            let mut chars = pascal.chars();
+            chars.next().map_or_else(String::new, |first| {
+                first.to_lowercase().chain(chars).collect()
+            })
+        }
+        Some("kebab-case") => name.replace('_', "-"),
+        Some("SCREAMING-KEBAB-CASE") => name.to_ascii_uppercase().replace('_', "-"),
+        _ => name,
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::HashSet;
+
+use proc_macro2::{TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::{GenericParam, Generics, Type, WherePredicate};
+
+/// Returns `generics` without bounds nor where-clause, for the generic
+/// parameters of type aliases, where bounds are not enforced.
+pub fn alias_generics(generics: &Generics) -> Generics {
+    let mut generics = generics.clone();
+    for param in generics.params.iter_mut() {
+        match param {
+            GenericParam::Type(type_param) => {
+                type_param.colon_token = None;
+                type_param.bounds.clear();
+            }
+            GenericParam::Lifetime(lifetime_param) => {
+                lifetime_param.colon_token = None;
+                lifetime_param.bounds.clear();
+            }
+            GenericParam::Const(_) => {}
+        }
+    }
+    generics.where_clause = None;
+    generics
+}
+
+/// Returns the subset of `generics` needed to name `ty`: the parameters used
+/// by `ty`, the parameters their bounds refer to, and the where-predicates
+/// that only involve those parameters.
+///
+/// Per-field wrapper types use this so that, e.g., the wrapper of
+/// `total: u64` in `struct Page<T>` stays non-generic.
+pub fn field_generics(generics: &Generics, ty: &Type) -> Generics {
+    let (mut idents, mut lifetimes) = (HashSet::new(), HashSet::new());
+    collect_names(ty.to_token_stream(), &mut idents, &mut lifetimes);
+
+    loop {
+        let before = idents.len() + lifetimes.len();
+        for param in generics.params.iter() {
+            if is_used(param, &idents, &lifetimes) {
+                collect_names(param.to_token_stream(), &mut idents, &mut lifetimes);
+            }
+        }
+        if idents.len() + lifetimes.len() == before {
+            break;
+        }
+    }
+
+    let mut subset = generics.clone();
+    subset.params = generics
+        .params
+        .iter()
+        .filter(|param| is_used(param, &idents, &lifetimes))
+        .cloned()
+        .collect();
+    if subset.params.is_empty() {
+        return Generics::default();
+    }
+
+    let kept: Vec<_> = subset.params.iter().map(param_name).collect();
+    let all: Vec<_> = generics.params.iter().map(param_name).collect();
+    if let Some(where_clause) = subset.where_clause.as_mut() {
+        where_clause.predicates = where_clause
+            .predicates
+            .iter()
+            .filter(|predicate| predicate_uses_only(predicate, &all, &kept))
+            .cloned()
+            .collect();
+    }
+    subset
+}
+
+fn is_used(param: &GenericParam, idents: &HashSet<String>, lifetimes: &HashSet<String>) -> bool {
+    match param {
+        GenericParam::Type(type_param) => idents.contains(&type_param.ident.to_string()),
+        GenericParam::Const(const_param) => idents.contains(&const_param.ident.to_string()),
+        GenericParam::Lifetime(lifetime_param) => {
+            lifetimes.contains(&lifetime_param.lifetime.ident.to_string())
+        }
+    }
+}
+
+fn param_name(param: &GenericParam) -> String {
+    match param {
+        GenericParam::Type(type_param) => type_param.ident.to_string(),
+        GenericParam::Const(const_param) => const_param.ident.to_string(),
+        GenericParam::Lifetime(lifetime_param) => format!("'{}", lifetime_param.lifetime.ident),
+    }
+}
+
+fn predicate_uses_only(predicate: &WherePredicate, all: &[String], kept: &[String]) -> bool {
+    let (mut idents, mut lifetimes) = (HashSet::new(), HashSet::new());
+    collect_names(predicate.to_token_stream(), &mut idents, &mut lifetimes);
+    let used = idents
+        .into_iter()
+        .chain(lifetimes.into_iter().map(|lifetime| format!("'{lifetime}")));
+    used.filter(|name| all.contains(name))
+        .all(|name| kept.contains(&name))
+}
+
+/// Collects every identifier and every lifetime name found in `tokens`.
+fn collect_names(
+    tokens: TokenStream,
+    idents: &mut HashSet<String>,
+    lifetimes: &mut HashSet<String>,
+) {
+    let mut after_quote = false;
+    for token in tokens {
+        match token {
+            TokenTree::Ident(ident) if after_quote => {
+                lifetimes.insert(ident.to_string());
+                after_quote = false;
+            }
+            TokenTree::Ident(ident) => {
+                idents.insert(ident.to_string());
+            }
+            TokenTree::Punct(punct) => after_quote = punct.as_char() == '\'',
+            TokenTree::Group(group) => {
+                collect_names(group.stream(), idents, lifetimes);
+                after_quote = false;
+            }
+            TokenTree::Literal(_) => after_quote = false,
+        }
+    }
+}
@@ -1,41 +1,21 @@
-#[cfg(feature = "mutex")]
-pub mod parse_mutex;
+pub mod attributes;
 
-#[cfg(feature = "arc")]
-pub mod parse_arc;
+pub mod capabilities;
 
-#[cfg(feature = "box")]
-pub mod parse_box;
+pub mod diagnostics;
 
-#[cfg(feature = "oncecell")]
-pub mod parse_once_cell;
+pub mod field_member;
 
-#[cfg(feature = "refcell")]
-pub mod parse_ref_cell;
-
-#[cfg(feature = "unsafecell")]
-pub mod parse_unsafe_cell;
-
-#[cfg(feature = "vec")]
-pub mod parse_vec;
-
-#[cfg(feature = "hashmap")]
-pub mod parse_hash_map;
-
-#[cfg(feature = "tokio")]
-pub mod parse_tokio_mutex;
-
-#[cfg(feature = "serde_json")]
-pub mod parse_json;
-
-#[cfg(feature = "bincode")]
-pub mod parse_bin;
-
-#[cfg(feature = "toml")]
-pub mod parse_toml;
+pub mod generics;
 
 pub mod parse;
 
 pub mod prelude;
 
 pub mod parse_by_field;
+
+pub mod parse_by_variant;
+
+pub mod prefixed_ident;
+
+pub mod visibility;
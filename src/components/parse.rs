@@ -1,175 +1,76 @@
 use mokuya::components::prelude::*;
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::DeriveInput;
+use syn::{Data, DeriveInput};
 
-use crate::generate_parse_by_field;
+use crate::{
+    alias_generics, field_name, generate_capabilities, generate_parse_by_field,
+    generate_parse_by_variant, generated_vis, get_field_attributes, prefixed_ident,
+    serde_field_name, serde_rename_all,
+};
 
+/// Generates the `parse()` entry point of the deriving type, the `{Prefix}Parse`
+/// alias of its `shori_core::Parse` wrapper, and the field or variant glue
+/// that backs the struct- and enum-specific methods of that wrapper.
 pub fn generate_parse(input: &DeriveInput) -> TokenStream {
     let struct_name = get_struct_name(input);
-    let impl_block = get_impl(input);
-    let fields = get_fields(input).expect("fields must be provided");
-    let field_names: Vec<_> = fields
-        .iter()
-        .map(|field| {
-            let field_name = &field.ident;
-            quote! { &self.0.#field_name }
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let alias_generics = alias_generics(&input.generics);
+    let parse = prefixed_ident(input, "Parse");
+    let vis = generated_vis(input);
+    let capabilities = prefixed_ident(input, "Capabilities");
+    let capability_markers = generate_capabilities(input);
+    let data_methods = match &input.data {
+        Data::Enum(_) => generate_parse_by_variant(input),
+        _ => generate_parse_by_field(input),
+    };
+    let type_name = struct_name.to_string();
+    let rename_all = serde_rename_all(input);
+    let field_infos: Vec<_> = get_fields(input)
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .filter_map(|(index, field)| {
+            let attributes = get_field_attributes(field).unwrap_or_default();
+            if attributes.skip {
+                return None;
+            }
+            let name = field_name(index, field);
+            let key = attributes
+                .rename
+                .map_or_else(|| name.clone(), |rename| rename.value());
+            let serde_name = serde_field_name(index, field, rename_all.as_deref());
+            let xml_attribute = attributes.xml_attribute.is_some();
+            Some(quote! {
+                ::shori_core::FieldInfo {
+                    name: #name,
+                    key: #key,
+                    serde_name: #serde_name,
+                    xml_attribute: #xml_attribute,
+                }
+            })
         })
         .collect();
-    let field_types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
-    let field_idents: Vec<_> = fields.iter().map(|field| &field.ident).collect();
-    let parse_by_field = generate_parse_by_field(input);
 
     quote! {
-        #[derive(Debug)]
         /// Wrapper type that provides parsing and transformation utilities
-        /// for the underlying struct.
-        pub struct Parse(#struct_name);
-        #parse_by_field
-
-        impl Parse {
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            pub fn field(self) -> FieldSelector {
-                FieldSelector(self)
-            }
-            #[cfg(feature="arc")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Converts the inner struct into an `Arc`, wrapped in `ParseArc`.
-            ///
-            /// Useful for thread-safe shared ownership.
-            pub fn arc(self) -> ParseArc {
-                ParseArc(std::sync::Arc::new(self.0))
-            }
-
-            #[cfg(feature = "tokio")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Converts into a `tokio::sync::Mutex`, wrapped in `ParseTokioMutex`.
-            ///
-            /// Useful for safe mutation across async tasks.
-            pub fn tokio_mutex(self) -> ParseTokioMutex {
-                ParseTokioMutex(tokio::sync::Mutex::new(self.0))
-            }
-
-            #[cfg(feature="hashmap")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Converts the struct fields into a `HashMap<String, Box<dyn Any + Send + Sync>>`.
-            ///
-            /// This enables dynamic access to fields by their name.
-            pub fn hashmap(self) -> ParseHashMap {
-                let mut map = std::collections::HashMap::new();
-                #(
-                    map.insert(
-                        stringify!(#field_idents).to_string(),
-                        Box::new(self.0.#field_idents) as Box<dyn std::any::Any + Send + Sync>
-                    );
-                )*
-                ParseHashMap(map)
-            }
-
-            #[cfg(feature="mutex")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Converts into a `std::sync::Mutex` wrapped in `ParseMutex`.
-            ///
-            /// Use this for interior mutability in synchronous code.
-            pub fn mutex(self) -> ParseMutex {
-                ParseMutex(std::sync::Mutex::new(self.0))
-            }
-
-            #[cfg(feature="box")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Boxes the inner struct into `Box<T>`, wrapped in `ParseBox`.
-            pub fn boxed(self) -> ParseBox {
-                ParseBox(Box::new(self.0))
-            }
-
-            #[cfg(feature="refcell")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Wraps the struct in a `RefCell`, allowing interior mutability in single-threaded contexts.
-            pub fn ref_cell(self) -> ParseRefCell {
-                ParseRefCell(std::cell::RefCell::new(self.0))
-            }
-
-            #[cfg(feature = "bincode")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Serializes the struct into binary using `bincode`, wrapped in `ParseBin`.
-            ///
-            /// # Errors
-            /// Returns an error if serialization fails.
-            pub fn bin(self) -> Result<ParseBin, Box<dyn std::error::Error>> {
-                let config = bincode::config::standard();
-                let serialized = bincode::encode_to_vec(&self.0, config)?;
-                Ok(ParseBin(serialized))
-            }
-
-            #[cfg(feature = "toml")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Serializes the struct into a `toml::Value`, wrapped in `ParseToml`.
-            ///
-            /// # Errors
-            /// Returns an error if TOML serialization or parsing fails.
-            pub fn toml(self) -> Result<ParseToml, Box<dyn std::error::Error>> {
-                let toml_string = toml::to_string(&self.0)?;
-                let value = toml::from_str::<toml::Value>(&toml_string)?;
-                Ok(ParseToml(value))
-            }
-
-            #[cfg(feature = "serde_json")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Serializes the struct into a `serde_json::Value`, wrapped in `ParseJson`.
-            ///
-            /// # Errors
-            /// Returns an error if JSON serialization fails.
-            pub fn json(self) -> Result<ParseJson, Box<dyn std::error::Error>> {
-                let json = serde_json::to_value(self.0)?;
-                Ok(ParseJson(json))
-            }
-
-            #[cfg(feature="vec")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Wraps the struct into a `Vec<T>`, containing a single element.
-            ///
-            /// Useful for APIs that expect list input.
-            pub fn vec(self) -> ParseVec {
-                ParseVec(vec![self.0])
-            }
-
-            #[cfg(feature="unsafecell")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Wraps the struct in an `UnsafeCell`.
-            ///
-            /// Allows unchecked interior mutability.
-            pub fn unsafe_cell(self) -> ParseUnsafeCell {
-                ParseUnsafeCell(std::cell::UnsafeCell::new(self.0))
-            }
-
-            #[cfg(feature="oncecell")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Initializes a `OnceCell` with the struct.
-            ///
-            /// The value is set once and subsequent attempts are ignored.
-            pub fn once_cell(self) -> ParseOnceCell {
-                let cell = std::cell::OnceCell::new();
-                let _ = cell.set(self.0);
-                ParseOnceCell(cell)
-            }
-
-            #[cfg(feature="tuple")]
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Returns a tuple of references to all the struct's fields.
-            ///
-            /// Useful for destructuring or pattern matching.
-            pub fn tuple(&self) -> (#(&#field_types),*) {
-                (#(#field_names),*)
-            }
+        /// for the underlying type.
+        #vis type #parse #alias_generics = ::shori_core::Parse<#struct_name #ty_generics>;
+        #data_methods
+        #capability_markers
+
+        impl #impl_generics ::shori_core::Metadata for #struct_name #ty_generics #where_clause {
+            const NAME: &'static str = #type_name;
+            const FIELDS: &'static [::shori_core::FieldInfo] = &[#(#field_infos),*];
+            type Capabilities = #capabilities;
         }
 
-        impl #impl_block {
-            #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-            /// Converts the original struct into a [`Parse`] wrapper.
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            /// Converts the original struct into its `Parse` wrapper.
             ///
             /// Enables access to parsing utilities and conversions.
-            pub fn parse(self) -> Parse {
-                Parse(self)
+            #vis fn parse(self) -> ::shori_core::Parse<Self> {
+                ::shori_core::Parse::new(self)
             }
         }
     }
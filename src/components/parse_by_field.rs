@@ -1,309 +1,143 @@
-use mokuya::components::prelude::{get_fields, get_struct_name, is_string};
-use quote::{format_ident, quote};
-use syn::DeriveInput;
-
+use mokuya::components::prelude::get_fields;
+use quote::quote;
+use syn::{DeriveInput, parse_quote};
+
+use crate::{
+    alias_generics, capability_selected, field_generics, field_member, field_method, field_name,
+    generated_vis, get_field_attributes, prefixed_ident, serde_field_name, serde_rename_all,
+};
+
+/// Generates the field selector of a struct, one `shori_core::FieldTag` and
+/// one `{Prefix}Field{Name}` alias per field, and the `shori_core::Fields` and
+/// `shori_core::IntoHashMap` implementations backing `field()`, `tuple()` and
+/// `hashmap()` on its `Parse` wrapper.
 pub fn generate_parse_by_field(input: &DeriveInput) -> proc_macro2::TokenStream {
-    let struct_ident = get_struct_name(input);
-    let struct_name_str = struct_ident.to_string();
-    let fields = get_fields(input).expect("fields must be provided");
-
-    let mut inner_wrappers = Vec::new();
-    let mut outer_wrappers = Vec::new();
+    let struct_name = &input.ident;
+    let Ok(fields) = get_fields(input) else {
+        return proc_macro2::TokenStream::new();
+    };
+    let selector = prefixed_ident(input, "Fields");
+    let capabilities = prefixed_ident(input, "Capabilities");
+    let vis = generated_vis(input);
+    let rename_all = serde_rename_all(input);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut field_items = Vec::new();
     let mut selector_methods = Vec::new();
-    let mut per_field_feature_wrappers = Vec::new();
-    let mut per_field_feature_methods = Vec::new();
-
-    for field in fields.iter() {
-        let field_ident = field.ident.as_ref().expect("named fields only");
+    let mut field_members = Vec::new();
+    let mut field_keys = Vec::new();
+    let mut field_types = Vec::new();
+
+    for (index, field) in fields.iter().enumerate() {
+        let attributes = get_field_attributes(field).unwrap_or_default();
+        if attributes.skip {
+            continue;
+        }
+        let field_member = field_member(index, field);
         let field_ty = &field.ty;
-
-        let field_name_str = field_ident.to_string();
+        let field_name_str = field_name(index, field);
+        let field_method = field_method(index, field);
+        let field_alias_generics = alias_generics(&field_generics(generics, field_ty));
+        let field_key = attributes
+            .rename
+            .map_or_else(|| field_name_str.clone(), |rename| rename.value());
+        let serde_name = serde_field_name(index, field, rename_all.as_deref());
+        field_members.push(field_member.clone());
+        field_keys.push(field_key.clone());
+        field_types.push(field_ty);
         let mut chars = field_name_str.chars();
         let capitalized = match chars.next() {
             Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
             None => field_name_str.clone(),
         };
 
-        let inner_ident = format_ident!("Parse{}{}", struct_name_str, capitalized);
-        let outer_ident = format_ident!("Field{}", capitalized);
-        let outer_ident_str = outer_ident.to_string();
-
-        inner_wrappers.push(quote! {
-            #[derive(Debug)]
-            pub struct #inner_ident(pub #field_ty);
-        });
-
-        outer_wrappers.push(quote! {
-            #[derive(Debug)]
-            pub struct #outer_ident(pub #inner_ident);
-        });
-
-        selector_methods.push(quote! {
-            pub fn #field_ident(self) -> #outer_ident {
-                #outer_ident(#inner_ident(self.0.0.#field_ident))
-            }
-        });
-
-        let arc_ident = format_ident!("{}Arc", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "arc")]
-            #[derive(Debug)]
-            pub struct #arc_ident(pub std::sync::Arc<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "arc")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn arc(self) -> #arc_ident {
-                    #arc_ident(std::sync::Arc::new(self.0.0))
-                }
+        let field_ident = prefixed_ident(input, &format!("Field{}", capitalized));
+        let tag_ident = prefixed_ident(input, &format!("Field{}Tag", capitalized));
+        let wrapper = quote! { ::shori_core::FieldWrapper<#field_ty, #tag_ident> };
+
+        field_items.push(quote! {
+            /// Marker type carrying the metadata of a single field.
+            #[derive(Debug, Clone, Copy)]
+            #[allow(non_camel_case_types)]
+            #vis struct #tag_ident;
+
+            impl ::shori_core::FieldTag for #tag_ident {
+                const NAME: &'static str = #field_name_str;
+                const KEY: &'static str = #field_key;
+                const SERDE_NAME: &'static str = #serde_name;
+                type Capabilities = #capabilities;
             }
-        });
 
-        let tokio_mutex_ident = format_ident!("{}TokioMutex", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "tokio")]
-            #[derive(Debug)]
-            pub struct #tokio_mutex_ident(pub tokio::sync::Mutex<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "tokio")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn tokio_mutex(self) -> #tokio_mutex_ident {
-                    #tokio_mutex_ident(tokio::sync::Mutex::new(self.0.0))
-                }
-            }
+            /// Wrapper of a single field, returned by the field selector.
+            #[allow(non_camel_case_types)]
+            #vis type #field_ident #field_alias_generics = #wrapper;
         });
 
-        let mutex_ident = format_ident!("{}Mutex", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "mutex")]
-            #[derive(Debug)]
-            pub struct #mutex_ident(pub std::sync::Mutex<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "mutex")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn mutex(self) -> #mutex_ident {
-                    #mutex_ident(std::sync::Mutex::new(self.0.0))
-                }
-            }
-        });
-
-        let box_ident = format_ident!("{}Box", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "box")]
-            #[derive(Debug)]
-            pub struct #box_ident(pub Box<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "box")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn boxed(self) -> #box_ident {
-                    #box_ident(Box::new(self.0.0))
-                }
+        selector_methods.push(quote! {
+            #vis fn #field_method(self) -> #wrapper {
+                ::shori_core::FieldWrapper::new(self.0.into_inner().#field_member)
             }
         });
+    }
 
-        let refcell_ident = format_ident!("{}RefCell", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "refcell")]
-            #[derive(Debug)]
-            pub struct #refcell_ident(pub std::cell::RefCell<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "refcell")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn ref_cell(self) -> #refcell_ident {
-                    #refcell_ident(std::cell::RefCell::new(self.0.0))
+    // Fields that are not `Send + Sync` only rule out `hashmap()`: the bounds
+    // are higher-ranked so that, for concrete field types, they are checked
+    // where `hashmap()` is called rather than failing the whole derive.
+    let into_hashmap = capability_selected(input, "hashmap").then(|| {
+        let mut hashmap_generics = generics.clone();
+        let hashmap_where_clause = hashmap_generics.make_where_clause();
+        for field_ty in field_types.iter() {
+            hashmap_where_clause
+                .predicates
+                .push(parse_quote! { for<'shori> #field_ty: Send + Sync + 'static });
+        }
+        quote! {
+            impl #impl_generics ::shori_core::IntoHashMap for #struct_name #ty_generics #hashmap_where_clause {
+                fn into_hashmap(self) -> std::collections::HashMap<String, Box<dyn std::any::Any + Send + Sync>> {
+                    #[allow(unused_mut)]
+                    let mut map = std::collections::HashMap::new();
+                    #(
+                        map.insert(
+                            #field_keys.to_string(),
+                            Box::new(self.#field_members) as Box<dyn std::any::Any + Send + Sync>
+                        );
+                    )*
+                    map
                 }
             }
-        });
+        }
+    });
 
-        let unsafecell_ident = format_ident!("{}UnsafeCell", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "unsafecell")]
-            #[derive(Debug)]
-            pub struct #unsafecell_ident(pub std::cell::UnsafeCell<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "unsafecell")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn unsafe_cell(self) -> #unsafecell_ident {
-                    #unsafecell_ident(std::cell::UnsafeCell::new(self.0.0))
-                }
-            }
-        });
+    quote! {
+        /// Field selector of the struct, returned by `.parse().field()`.
+        #[derive(Debug)]
+        #vis struct #selector #generics (::shori_core::Parse<#struct_name #ty_generics>) #where_clause;
+        #(#field_items)*
 
-        let oncecell_ident = format_ident!("{}OnceCell", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "oncecell")]
-            #[derive(Debug)]
-            pub struct #oncecell_ident(pub std::cell::OnceCell<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "oncecell")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn once_cell(self) -> #oncecell_ident {
-                    let cell = std::cell::OnceCell::new();
-                    let _ = cell.set(self.0.0);
-                    #oncecell_ident(cell)
-                }
+        impl #impl_generics #selector #ty_generics #where_clause {
+            /// Returns the `Parse` wrapper the selector was created from.
+            #vis fn into_parse(self) -> ::shori_core::Parse<#struct_name #ty_generics> {
+                self.0
             }
-        });
 
-        let vec_ident = format_ident!("{}Vec", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "vec")]
-            #[derive(Debug)]
-            pub struct #vec_ident(pub Vec<#field_ty>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "vec")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn vec(self) -> #vec_ident {
-                    #vec_ident(vec![self.0.0])
-                }
-            }
-        });
+            #(#selector_methods)*
+        }
 
-        let bin_ident = format_ident!("{}Bin", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "bincode")]
-            #[derive(Debug)]
-            pub struct #bin_ident(pub Vec<u8>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "bincode")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn bin(self) -> Result<#bin_ident, Box<dyn std::error::Error>> {
-                    let config = bincode::config::standard();
-                    let serialized = bincode::encode_to_vec(&self.0.0, config)?;
-                    Ok(#bin_ident(serialized))
-                }
-            }
-        });
+        impl #impl_generics ::shori_core::Fields for #struct_name #ty_generics #where_clause {
+            type Selector = #selector #ty_generics;
+            type Tuple<'shori> = (#(&'shori #field_types,)*) where Self: 'shori;
 
-        let json_ident = format_ident!("{}Json", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "serde_json")]
-            #[derive(Debug)]
-            pub struct #json_ident(pub serde_json::Value);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "serde_json")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn json(self) -> Result<#json_ident, Box<dyn std::error::Error>> {
-                    let json = serde_json::to_value(self.0.0)?;
-                    Ok(#json_ident(json))
-                }
+            fn selector(parse: ::shori_core::Parse<Self>) -> Self::Selector {
+                #selector(parse)
             }
-        });
 
-        let hashmap_ident = format_ident!("{}HashMap", outer_ident_str);
-        per_field_feature_wrappers.push(quote! {
-            #[cfg(feature = "hashmap")]
-            #[derive(Debug)]
-            pub struct #hashmap_ident(pub std::collections::HashMap<String, Box<dyn std::any::Any + Send + Sync>>);
-        });
-        per_field_feature_methods.push(quote! {
-            #[cfg(feature = "hashmap")]
-            impl #outer_ident {
-                #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                pub fn hashmap(self) -> #hashmap_ident {
-                    let mut map = std::collections::HashMap::new();
-                    map.insert(
-                        stringify!(#field_ident).to_string(),
-                        Box::new(self.0.0) as Box<dyn std::any::Any + Send + Sync>
-                    );
-                    #hashmap_ident(map)
-                }
+            #[allow(clippy::unused_unit)]
+            fn tuple(&self) -> Self::Tuple<'_> {
+                (#(&self.#field_members,)*)
             }
-        });
-
-        if is_string(field_ty) {
-            let argon_ident = format_ident!("{}Argon2", outer_ident_str);
-
-            per_field_feature_wrappers.push(quote! {
-                #[cfg(feature = "argon2")]
-                #[derive(Debug)]
-                pub struct #argon_ident(pub String);
-            });
-
-            per_field_feature_methods.push(quote! {
-                #[cfg(feature = "argon2")]
-                impl #outer_ident {
-                    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                    pub fn argon2_hash(self) -> Result<#argon_ident, Box<dyn std::error::Error + Send + Sync>> {
-                        use argon2::{
-                            Argon2, Algorithm, Version, Params,
-                            password_hash::{SaltString, PasswordHasher, rand_core::OsRng},
-                        };
-                        use std::io::{Error as IoError, ErrorKind};
-
-                        let m_cost_kib: u32 = 65_536;
-                        let t_cost: u32 = 3;
-                        let p_cost: u32 = match std::thread::available_parallelism() {
-                            Ok(nz) => {
-                                let v = nz.get() as u32;
-                                if v == 0 { 1 } else { std::cmp::min(v, 4) }
-                            }
-                            Err(_) => 1,
-                        };
-
-                        let params = Params::new(m_cost_kib, t_cost, p_cost, Some(32))
-                            .map_err(|e| Box::new(IoError::new(ErrorKind::Other, e.to_string())) as Box<dyn std::error::Error + Send + Sync>)?;
-                        let argon = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
-
-                        let salt = SaltString::generate(&mut OsRng);
-                        let phc = argon
-                            .hash_password(self.0 .0.as_bytes(), &salt)
-                            .map_err(|e| Box::new(IoError::new(ErrorKind::Other, e.to_string())) as Box<dyn std::error::Error + Send + Sync>)?;
-
-                        Ok(#argon_ident(phc.to_string()))
-                    }
-                }
-
-            #[cfg(feature = "argon2")]
-            impl #argon_ident {
-                    #[cfg_attr(feature = "nekotracing", nekotracing::nekotracing)]
-                    pub fn verify(&self, candidate: &str) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
-                        use argon2::{Argon2, password_hash::PasswordHash, password_hash::PasswordVerifier};
-                        use std::io::{Error as IoError, ErrorKind};
-
-                        let parsed = PasswordHash::new(&self.0)
-                            .map_err(|e| Box::new(IoError::new(ErrorKind::Other, e.to_string())) as Box<dyn std::error::Error + Send + Sync>)?;
-
-                        let argon = Argon2::default();
-
-                        match argon.verify_password(candidate.as_bytes(), &parsed) {
-                            Ok(()) => Ok(true),
-                            Err(_) => Ok(false),
-                        }
-                    }
-                }
-            });
         }
-    }
 
-    quote! {
-        #[derive(Debug)]
-        pub struct FieldSelector(pub Parse);
-        #(#inner_wrappers)*
-        #(#outer_wrappers)*
-        #(#per_field_feature_wrappers)*
-        impl FieldSelector {
-            #(#selector_methods)*
-        }
-        #(#per_field_feature_methods)*
+        #into_hashmap
     }
 }
@@ -0,0 +1,140 @@
+use mokuya::components::prelude::get_struct_name;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Data, DeriveInput, Fields};
+
+use crate::{generated_vis, get_variant_attributes, prefixed_ident};
+
+/// Generates the variant selector of an enum and its `shori_core::Variants`
+/// implementation, which backs `variant()`, `variant_name()` and `VARIANTS`
+/// on its `Parse` wrapper.
+pub fn generate_parse_by_variant(input: &DeriveInput) -> TokenStream {
+    let Data::Enum(data) = &input.data else {
+        return TokenStream::new();
+    };
+    let enum_name = get_struct_name(input);
+    let selector = prefixed_ident(input, "Variants");
+    let vis = generated_vis(input);
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let mut variant_names = Vec::new();
+    let mut name_arms = Vec::new();
+    let mut selector_methods = Vec::new();
+
+    for variant in data.variants.iter() {
+        let attributes = get_variant_attributes(variant).unwrap_or_default();
+        let variant_ident = &variant.ident;
+        let variant_name = attributes
+            .rename
+            .map_or_else(|| variant_ident.to_string(), |rename| rename.value());
+
+        let pattern = match &variant.fields {
+            Fields::Named(_) => quote! { #enum_name::#variant_ident { .. } },
+            Fields::Unnamed(_) => quote! { #enum_name::#variant_ident(..) },
+            Fields::Unit => quote! { #enum_name::#variant_ident },
+        };
+        name_arms.push(quote! { #pattern => #variant_name });
+        variant_names.push(variant_name.clone());
+
+        if attributes.skip {
+            continue;
+        }
+
+        let snake_name = to_snake_case(&variant_ident.to_string());
+        let as_ident = format_ident!("as_{}", snake_name);
+        let is_ident = format_ident!("is_{}", snake_name);
+        let field_types: Vec<_> = variant.fields.iter().map(|field| &field.ty).collect();
+        let bindings: Vec<_> = (0..field_types.len())
+            .map(|index| format_ident!("field_{}", index))
+            .collect();
+        let destructure = match &variant.fields {
+            Fields::Named(fields) => {
+                let field_idents = fields.named.iter().map(|field| &field.ident);
+                quote! { #enum_name::#variant_ident { #(#field_idents: #bindings),* } }
+            }
+            Fields::Unnamed(_) => quote! { #enum_name::#variant_ident(#(#bindings),*) },
+            Fields::Unit => quote! { #enum_name::#variant_ident },
+        };
+        let (output_ty, output) = match field_types.as_slice() {
+            [field_ty] => (quote! { #field_ty }, quote! { #(#bindings)* }),
+            _ => (quote! { (#(#field_types),*) }, quote! { (#(#bindings),*) }),
+        };
+        let as_doc = format!(
+            "Returns the fields of the `{}` variant, or `None` if the value holds another variant.",
+            variant_name
+        );
+        let is_doc = format!(
+            "Returns `true` if the value holds the `{}` variant.",
+            variant_name
+        );
+
+        selector_methods.push(quote! {
+            #[doc = #as_doc]
+            #[allow(unreachable_patterns)]
+            #vis fn #as_ident(self) -> Option<#output_ty> {
+                match self.0.into_inner() {
+                    #destructure => Some(#output),
+                    _ => None,
+                }
+            }
+
+            #[doc = #is_doc]
+            #vis fn #is_ident(&self) -> bool {
+                matches!(self.0.get(), #pattern)
+            }
+        });
+    }
+
+    quote! {
+        #[derive(Debug)]
+        #vis struct #selector #generics (::shori_core::Parse<#enum_name #ty_generics>) #where_clause;
+
+        impl #impl_generics #selector #ty_generics #where_clause {
+            /// Returns the `Parse` wrapper the selector was created from.
+            #vis fn into_parse(self) -> ::shori_core::Parse<#enum_name #ty_generics> {
+                self.0
+            }
+
+            #(#selector_methods)*
+        }
+
+        impl #impl_generics ::shori_core::Variants for #enum_name #ty_generics #where_clause {
+            const VARIANTS: &'static [&'static str] = &[#(#variant_names),*];
+            type Selector = #selector #ty_generics;
+
+            fn selector(parse: ::shori_core::Parse<Self>) -> Self::Selector {
+                #selector(parse)
+            }
+
+            fn variant_name(&self) -> &'static str {
+                match self {
+                    #(#name_arms,)*
+                }
+            }
+        }
+    }
+}
+
+/// Converts a `CamelCase` variant name into `snake_case`, keeping acronyms
+/// together (`HTTPError` => `http_error`).
+fn to_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (index, ch) in chars.iter().enumerate() {
+        if ch.is_uppercase() {
+            let prev_is_lower =
+                index > 0 && (chars[index - 1].is_lowercase() || chars[index - 1].is_ascii_digit());
+            let acronym_end = index > 0
+                && chars[index - 1].is_uppercase()
+                && chars.get(index + 1).is_some_and(|next| next.is_lowercase());
+            if prev_is_lower || acronym_end {
+                snake.push('_');
+            }
+            snake.extend(ch.to_lowercase());
+        } else {
+            snake.push(*ch);
+        }
+    }
+    snake
+}
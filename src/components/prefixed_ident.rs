@@ -0,0 +1,19 @@
+use proc_macro2::Ident;
+use quote::format_ident;
+use syn::DeriveInput;
+
+use crate::get_container_attributes;
+
+/// Builds the identifier of a generated item by prefixing `suffix` with the
+/// name of the deriving type, e.g. `User` + `ParseJson` => `UserParseJson`.
+///
+/// The prefix can be overridden with `#[parser(name = "...")]`. Every type
+/// emitted by `#[derive(Parser)]` goes through this helper so that any number
+/// of derived types can live in the same module.
+pub fn prefixed_ident(input: &DeriveInput, suffix: &str) -> Ident {
+    let prefix = get_container_attributes(input)
+        .ok()
+        .and_then(|attributes| attributes.name)
+        .unwrap_or_else(|| input.ident.clone());
+    format_ident!("{}{}", prefix, suffix)
+}
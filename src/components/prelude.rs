@@ -1,39 +1,19 @@
-pub use super::parse::*;
-
-pub use super::parse_by_field::*;
-
-#[cfg(feature = "arc")]
-pub use super::parse_arc::*;
+pub use super::attributes::*;
 
-#[cfg(feature = "box")]
-pub use super::parse_box::*;
+pub use super::capabilities::*;
 
-#[cfg(feature = "hashmap")]
-pub use super::parse_hash_map::*;
+pub use super::diagnostics::*;
 
-#[cfg(feature = "mutex")]
-pub use super::parse_mutex::*;
+pub use super::field_member::*;
 
-#[cfg(feature = "oncecell")]
-pub use super::parse_once_cell::*;
+pub use super::generics::*;
 
-#[cfg(feature = "refcell")]
-pub use super::parse_ref_cell::*;
-
-#[cfg(feature = "unsafecell")]
-pub use super::parse_unsafe_cell::*;
-
-#[cfg(feature = "vec")]
-pub use super::parse_vec::*;
+pub use super::parse::*;
 
-#[cfg(feature = "serde_json")]
-pub use super::parse_json::*;
+pub use super::parse_by_field::*;
 
-#[cfg(feature = "tokio")]
-pub use super::parse_tokio_mutex::*;
+pub use super::parse_by_variant::*;
 
-#[cfg(feature = "toml")]
-pub use super::parse_toml::*;
+pub use super::prefixed_ident::*;
 
-#[cfg(feature = "bincode")]
-pub use super::parse_bin::*;
+pub use super::visibility::*;
@@ -0,0 +1,13 @@
+use syn::{DeriveInput, Visibility};
+
+use crate::get_container_attributes;
+
+/// Returns the visibility of the items generated for `input`: the one given
+/// with `#[parser(vis = "...")]`, or the visibility of the deriving type so
+/// that a private model does not leak through its generated wrappers.
+pub fn generated_vis(input: &DeriveInput) -> Visibility {
+    get_container_attributes(input)
+        .ok()
+        .and_then(|attributes| attributes.vis)
+        .unwrap_or_else(|| input.vis.clone())
+}
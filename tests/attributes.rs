@@ -0,0 +1,183 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+#[parser(name = "Account")]
+pub struct User {
+    #[parser(rename = "userId")]
+    pub id: String,
+    pub name: String,
+    #[parser(skip)]
+    pub password: String,
+}
+
+fn user() -> User {
+    User {
+        id: "1".into(),
+        name: "John Doe".into(),
+        password: "password123".into(),
+    }
+}
+
+#[test]
+fn container_name() {
+    let parse: AccountParse = user().parse();
+    let json: shori_core::ParseJson<User> = parse.json().unwrap();
+    assert_eq!(json.from().unwrap(), user());
+
+    let name: AccountFieldName = user().parse().field().name();
+    assert_eq!(name.into_inner(), "John Doe");
+}
+
+#[test]
+fn field_rename() {
+    let map = user().parse().field().id().hashmap();
+    assert!(map.contains_key("userId"));
+    assert!(!map.contains_key("id"));
+}
+
+#[test]
+fn skip_and_rename_in_struct_outputs() {
+    let parse = user().parse();
+    let (id, name) = parse.tuple();
+    assert_eq!((id.as_str(), name.as_str()), ("1", "John Doe"));
+
+    let map = user().parse().hashmap();
+    assert_eq!(map.get::<String>("userId").unwrap(), "1");
+    assert_eq!(map.get::<String>("name").unwrap(), "John Doe");
+    assert!(!map.contains_key("id"));
+    assert!(!map.contains_key("password"));
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct Profile {
+    pub user_id: u32,
+    #[serde(rename(serialize = "displayName", deserialize = "name"), default)]
+    #[parser(rename = "display")]
+    pub display_name: String,
+    pub r#type: String,
+}
+
+#[test]
+fn serde_names_in_field_metadata() {
+    use shori_core::Metadata;
+
+    let keys: Vec<_> = Profile::FIELDS
+        .iter()
+        .map(|field| (field.name, field.key, field.serde_name))
+        .collect();
+    assert_eq!(
+        keys,
+        [
+            ("user_id", "user_id", "userId"),
+            ("display_name", "display", "displayName"),
+            ("type", "type", "type"),
+        ]
+    );
+    let json = serde_json::to_value(Profile {
+        user_id: 1,
+        display_name: "x".into(),
+        r#type: "y".into(),
+    })
+    .unwrap();
+    for field in Profile::FIELDS {
+        assert!(json.get(field.serde_name).is_some());
+    }
+}
+
+#[derive(Parser, Debug, Clone)]
+pub struct Handle {
+    pub id: u32,
+    #[parser(skip)]
+    pub cache: std::rc::Rc<Vec<u8>>,
+}
+
+#[test]
+fn skipped_fields_need_not_be_send() {
+    let handle = Handle {
+        id: 7,
+        cache: std::rc::Rc::new(vec![1, 2, 3]),
+    };
+    let parse = handle.clone().parse();
+    let (id,) = parse.tuple();
+    assert_eq!(*id, 7);
+    let map = handle.parse().hashmap();
+    assert_eq!(map.get::<u32>("id"), Some(&7));
+    assert_eq!(map.keys().count(), 1);
+}
+
+#[derive(Parser, Debug, Clone, PartialEq)]
+pub struct Local {
+    pub shared: std::rc::Rc<String>,
+    pub count: u32,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq)]
+#[parser(exclude(hashmap))]
+pub struct Pinned {
+    pub handle: std::rc::Rc<u8>,
+    pub count: u32,
+}
+
+#[test]
+fn fields_that_are_not_send_only_rule_out_hashmap() {
+    let local = Local {
+        shared: std::rc::Rc::new("x".into()),
+        count: 2,
+    };
+    let map = local.clone().parse().field().count().hashmap();
+    assert_eq!(map.get::<u32>("count"), Some(&2));
+    assert_eq!(*local.clone().parse().boxed().get().shared, "x");
+    assert_eq!(local.parse().arc().get().count, 2);
+
+    let pinned = Pinned {
+        handle: std::rc::Rc::new(1),
+        count: 3,
+    };
+    let parse = pinned.parse();
+    let (handle, count) = parse.tuple();
+    assert_eq!((**handle, *count), (1, 3));
+}
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct Token {
+    pub r#type: String,
+}
+
+#[test]
+fn raw_identifier_field() {
+    let token = Token {
+        r#type: "bearer".into(),
+    };
+    let map = token.clone().parse().field().r#type().hashmap();
+    assert!(map.contains_key("type"));
+    let field: TokenFieldType = token.parse().field().r#type();
+    assert_eq!(field.key(), "type");
+    assert_eq!(field.into_inner(), "bearer");
+}
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+#[parser(only(json, bin, arc))]
+pub struct Session {
+    pub token: String,
+}
+
+#[test]
+fn only_selected_capabilities() {
+    let session = Session {
+        token: "abc".into(),
+    };
+    let json = session.clone().parse().json().unwrap();
+    assert_eq!(json.from().unwrap(), session);
+    let bin = session.clone().parse().bin().unwrap();
+    assert_eq!(bin.from().unwrap(), session);
+    assert_eq!(session.clone().parse().arc().get().token, "abc");
+    assert_eq!(*session.parse().field().token().arc().get(), "abc");
+}
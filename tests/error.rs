@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+use shori_core::{Error, Format, Operation};
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct User {
+    pub id: String,
+    #[parser(rename = "grid")]
+    pub cells: BTreeMap<(u8, u8), u8>,
+}
+
+fn user() -> User {
+    User {
+        id: "1".into(),
+        cells: BTreeMap::from([((0, 0), 1)]),
+    }
+}
+
+fn assert_send_sync<T: Send + Sync + 'static>() {}
+
+#[test]
+fn error_is_send_and_sync() {
+    assert_send_sync::<Error>();
+}
+
+#[test]
+fn encode_error_carries_field_path() {
+    let error = user().parse().field().cells().json().unwrap_err();
+    assert_eq!(error.format(), Format::Json);
+    assert_eq!(error.operation(), Operation::Encode);
+    assert_eq!(error.path(), Some("grid"));
+    assert!(
+        error
+            .to_string()
+            .starts_with("failed to encode JSON at `grid`: ")
+    );
+    assert!(std::error::Error::source(&error).is_some());
+
+    let error = user().parse().json().unwrap_err();
+    assert_eq!(error.path(), None);
+}
+
+#[test]
+fn decode_errors_share_the_error_type() {
+    let bin = user().parse().bin().unwrap();
+    let error = bin.from_bytes(&[0xff]).unwrap_err();
+    assert_eq!(
+        (error.format(), error.operation()),
+        (Format::Bincode, Operation::Decode)
+    );
+
+    let error = bin.from_hex("not hex").unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Codec {
+            format: Format::Hex,
+            operation: Operation::Decode,
+            ..
+        }
+    ));
+}
+
+#[tokio::test]
+async fn question_mark_across_tasks() -> Result<(), Error> {
+    let user = tokio::spawn(async move {
+        let bin = user().parse().bin()?;
+        bin.from()
+    })
+    .await
+    .unwrap()?;
+    assert_eq!(user, self::user());
+    Ok(())
+}
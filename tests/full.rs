@@ -1,8 +1,7 @@
-#![cfg(feature = "full")]
-
 use kenzu::Builder;
 use serde::{Deserialize, Serialize};
 use shori::Parser;
+use std::any::Any;
 
 #[derive(
     Builder,
@@ -462,7 +461,6 @@ async fn use_field_tokio_mutex_and_tuple() -> Result<(), String> {
     assert_eq!(age, &25);
     assert_eq!(gender, "F");
 
-    #[cfg(feature = "tokio")]
     {
         let name_tokio = User::new()
             .id(UserId::new("123e4567-e89b-12d3-a456-426614174000")?)
@@ -476,7 +474,7 @@ async fn use_field_tokio_mutex_and_tuple() -> Result<(), String> {
             .name()
             .tokio_mutex();
 
-        let mut locked = name_tokio.0.lock().await;
+        let mut locked = name_tokio.as_inner().lock().await;
         assert_eq!(locked.as_str(), "John Doe");
         *locked = "Jane Doe".into();
         assert_eq!(locked.as_str(), "Jane Doe");
@@ -496,31 +494,27 @@ fn use_field_basic_and_wrappers() -> Result<(), String> {
         .gender(UserGender::new("F")?);
 
     let wrapped = base.clone().parse().field().name();
-    match wrapped {
-        FieldName(ParseUserName(inner)) => {
-            assert_eq!(inner, "John Doe");
-        }
-    }
+    assert_eq!(wrapped.into_inner(), "John Doe");
 
     let name_arc = base.clone().parse().field().name().arc();
-    assert_eq!(&*name_arc.0, "John Doe");
+    assert_eq!(name_arc.as_inner().as_str(), "John Doe");
 
     let name_box = base.clone().parse().field().name().boxed();
-    assert_eq!(*name_box.0, "John Doe");
+    assert_eq!(name_box.as_inner().as_str(), "John Doe");
 
     let name_cell = base.clone().parse().field().name().ref_cell();
     {
-        let mut borrow = name_cell.0.borrow_mut();
+        let mut borrow = name_cell.as_inner().borrow_mut();
         *borrow = "Jane Doe".into();
     }
-    assert_eq!(name_cell.0.borrow().as_str(), "Jane Doe");
+    assert_eq!(name_cell.as_inner().borrow().as_str(), "Jane Doe");
 
     let name_once = base.clone().parse().field().name().once_cell();
-    assert_eq!(name_once.0.get().unwrap(), "John Doe");
+    assert_eq!(name_once.get().unwrap(), "John Doe");
 
     let name_vec = base.clone().parse().field().name().vec();
-    assert_eq!(name_vec.0.len(), 1);
-    assert_eq!(name_vec.0[0], "John Doe");
+    assert_eq!(name_vec.as_inner().len(), 1);
+    assert_eq!(name_vec.as_inner()[0], "John Doe");
 
     let name_bin = base
         .clone()
@@ -529,7 +523,7 @@ fn use_field_basic_and_wrappers() -> Result<(), String> {
         .name()
         .bin()
         .map_err(|e| e.to_string())?;
-    assert!(!name_bin.0.is_empty());
+    assert!(!name_bin.get().is_empty());
 
     let name_json = base
         .clone()
@@ -538,10 +532,10 @@ fn use_field_basic_and_wrappers() -> Result<(), String> {
         .name()
         .json()
         .map_err(|e| e.to_string())?;
-    assert_eq!(name_json.0, "John Doe");
+    assert_eq!(*name_json.get(), "John Doe");
 
     let hm = base.clone().parse().field().name().hashmap();
-    let boxed = hm.0.get("name").expect("expected key 'name'");
+    let boxed = hm.as_inner().get("name").expect("expected key 'name'");
     let any_ref: &dyn Any = &**boxed;
     if let Some(s) = any_ref.downcast_ref::<String>() {
         assert_eq!(s.as_str(), "John Doe");
@@ -550,13 +544,13 @@ fn use_field_basic_and_wrappers() -> Result<(), String> {
     }
 
     let uc = base.clone().parse().field().name().unsafe_cell();
-    let ptr = uc.0.get();
+    let ptr = uc.as_inner().get();
     unsafe {
         assert_eq!(&*ptr, "John Doe");
     }
 
     let m = base.clone().parse().field().name().mutex();
-    let guard = m.0.lock().unwrap();
+    let guard = m.as_inner().lock().unwrap();
     assert_eq!(&*guard, "John Doe");
 
     Ok(())
@@ -569,52 +563,48 @@ fn serialization_wrappers() -> Result<(), Box<dyn std::error::Error>> {
         .name(UserName::new("John Doe")?);
 
     let name_bin = base.clone().parse().field().name().bin()?;
-    assert!(!name_bin.0.is_empty());
+    assert!(!name_bin.get().is_empty());
 
     let name_json = base.parse().field().name().json()?;
-    assert_eq!(name_json.0, "John Doe");
+    assert_eq!(*name_json.get(), "John Doe");
 
     Ok(())
 }
 
 #[test]
-#[cfg(feature = "mutex")]
 fn mutex_wrappers() {
     let base = User::new().name(UserName::new("John Doe").unwrap());
     let m = base.parse().field().name().mutex();
-    let guard = m.0.lock().unwrap();
+    let guard = m.as_inner().lock().unwrap();
     assert_eq!(&*guard, "John Doe");
 }
 
 #[test]
-#[cfg(feature = "tokio")]
 fn tokio_mutex_wrappers() {
     let rt = tokio::runtime::Runtime::new().unwrap();
     let base = User::new().name(UserName::new("John Doe").unwrap());
     rt.block_on(async {
         let tm = base.parse().field().name().tokio_mutex();
-        let guard = tm.0.lock().await;
+        let guard = tm.as_inner().lock().await;
         assert_eq!(&*guard, "John Doe");
     });
 }
 
 #[test]
-#[cfg(feature = "unsafecell")]
 fn unsafe_cell_wrappers() {
     let base = User::new().name(UserName::new("John Doe").unwrap());
     let uc = base.parse().field().name().unsafe_cell();
-    let ptr = uc.0.get();
+    let ptr = uc.as_inner().get();
     unsafe {
         assert_eq!(&*ptr, "John Doe");
     }
 }
 
 #[test]
-#[cfg(feature = "argon2")]
 fn argon2_wrappers() {
     let base = User::new().password(UserPassword::new("password123").unwrap());
     let phc = base.parse().field().password().argon2_hash().unwrap();
-    assert!(phc.0.contains("$argon2"));
+    assert!(phc.get().contains("$argon2"));
     assert!(phc.verify("password123").unwrap());
     assert!(!phc.verify("wrong-password").unwrap());
 }
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct Order {
+    pub id: String,
+    pub total: u64,
+}
+
+#[test]
+fn structs_in_same_module() {
+    let user = User {
+        id: "1".into(),
+        name: "John Doe".into(),
+    };
+    let order = Order {
+        id: "2".into(),
+        total: 99,
+    };
+
+    let user_json: shori_core::ParseJson<User> = user.clone().parse().json().unwrap();
+    let order_json: shori_core::ParseJson<Order> = order.clone().parse().json().unwrap();
+    assert_eq!(user_json.from().unwrap(), user);
+    assert_eq!(order_json.from().unwrap(), order);
+
+    let user_bin: shori_core::ParseBin<User> = user.clone().parse().bin().unwrap();
+    let order_bin: shori_core::ParseBin<Order> = order.clone().parse().bin().unwrap();
+    assert_eq!(user_bin.from().unwrap(), user);
+    assert_eq!(order_bin.from().unwrap(), order);
+
+    let user_id: UserFieldId = user.clone().parse().field().id();
+    let order_id: OrderFieldId = order.clone().parse().field().id();
+    assert_eq!(user_id.into_inner(), "1");
+    assert_eq!(order_id.into_inner(), "2");
+
+    let user_map: shori_core::ParseHashMap = user.parse().hashmap();
+    let order_map: shori_core::ParseHashMap = order.parse().hashmap();
+    assert_eq!(user_map.get::<String>("name").unwrap(), "John Doe");
+    assert_eq!(order_map.get::<u64>("total").unwrap(), &99);
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use shori::Parser;
+use shori_core::{FieldInfo, Parsable, ParseJson, capabilities};
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct User {
+    #[parser(rename = "userId")]
+    pub id: String,
+    pub name: String,
+    #[parser(skip)]
+    pub password: String,
+}
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub enum Status {
+    Active,
+    Banned(String),
+}
+
+fn persist<T: Parsable<Capabilities: capabilities::Json> + Serialize>(
+    value: T,
+) -> shori_core::Result<T::Json> {
+    value.json()
+}
+
+fn round_trip<T>(value: T) -> T
+where
+    T: Parsable<Capabilities: capabilities::Json> + Serialize + DeserializeOwned,
+{
+    let json: ParseJson<T> = value.parse().json().unwrap();
+    json.from().unwrap()
+}
+
+fn user() -> User {
+    User {
+        id: "1".into(),
+        name: "John Doe".into(),
+        password: "password123".into(),
+    }
+}
+
+#[test]
+fn generic_over_derived_types() {
+    assert!(persist(user()).is_ok());
+    assert!(persist(Status::Banned("spam".into())).is_ok());
+    assert_eq!(round_trip(user()), user());
+    assert_eq!(round_trip(Status::Active), Status::Active);
+}
+
+#[test]
+fn metadata() {
+    assert_eq!(User::type_name(), "User");
+    assert_eq!(
+        User::fields(),
+        &[
+            FieldInfo {
+                name: "id",
+                key: "userId",
+                serde_name: "id",
+                xml_attribute: false,
+            },
+            FieldInfo {
+                name: "name",
+                key: "name",
+                serde_name: "name",
+                xml_attribute: false,
+            },
+        ]
+    );
+    assert_eq!(Status::type_name(), "Status");
+    assert!(Status::fields().is_empty());
+}
@@ -1,35 +1,32 @@
-#![cfg(all(feature = "arc", feature = "mutex", not(feature = "full")))]
 use kenzu::Builder;
 use shori::Parser;
 
-#[derive(Builder, PartialEq, Default, Parser, Debug, Clone)]
+#[derive(Builder, PartialEq, Parser, Debug, Clone)]
 pub struct User {
     pub id: String,
-    #[set(value = "name")]
+    #[opt(default = "name")]
     pub name: String,
     pub password: String,
-    #[build(
+    #[opt(
         pattern = r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$",
-        err = "err"
+        err = "err",
+        default = "email@example.com"
     )]
-    #[set(value = "email@example.com")]
     pub email: String,
-    #[set(value = 18)]
+    #[opt(default = 18)]
     pub age: u8,
     pub gender: String,
 }
 
 #[test]
-fn parse_arc_mutex() {
+fn parse_arc_mutex() -> Result<(), String> {
     let user = User::new()
-        .id("123e4567-e89b-12d3-a456-426614174000")
-        .name("John Doe")
-        .password("password123")
-        .email("johndoe@example.com")
-        .age(25)
-        .gender("F")
-        .build()
-        .unwrap()
+        .id(UserId::new("123e4567-e89b-12d3-a456-426614174000")?)
+        .name(UserName::new("John Doe")?)
+        .password(UserPassword::new("password123")?)
+        .email(UserEmail::new("johndoe@example.com")?)
+        .age(UserAge::new(25)?)
+        .gender(UserGender::new("F")?)
         .parse()
         .mutex()
         .arc();
@@ -42,4 +39,6 @@ fn parse_arc_mutex() {
     assert_eq!(locked_user.email, "johndoe@example.com");
     assert_eq!(locked_user.age, 25);
     assert_eq!(locked_user.gender, "F");
+
+    Ok(())
 }
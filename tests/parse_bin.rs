@@ -1,5 +1,3 @@
-#![cfg(all(feature = "serde", feature = "bincode", not(feature = "full")))]
-
 use kenzu::Builder;
 use serde::{Deserialize, Serialize};
 use shori::Parser;
@@ -7,7 +5,6 @@ use shori::Parser;
 #[derive(
     Builder,
     PartialEq,
-    Default,
     Parser,
     Debug,
     Clone,
@@ -18,37 +15,35 @@ use shori::Parser;
 )]
 pub struct User {
     pub id: String,
-    #[set(value = "name")]
+    #[opt(default = "name")]
     pub name: String,
     pub password: String,
-    #[build(
+    #[opt(
         pattern = r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$",
-        err = "err"
+        err = "err",
+        default = "email@example.com"
     )]
-    #[set(value = "email@example.com")]
     pub email: String,
-    #[set(value = 18)]
+    #[opt(default = 18)]
     pub age: u8,
     pub gender: String,
 }
 
 #[test]
-fn parse_bin() {
+fn parse_bin() -> Result<(), String> {
     let user_parse_bin = User::new()
-        .id("123e4567-e89b-12d3-a456-426614174000")
-        .name("John Doe")
-        .password("password123")
-        .email("johndoe@example.com")
-        .age(25)
-        .gender("F")
-        .build()
-        .unwrap()
+        .id(UserId::new("123e4567-e89b-12d3-a456-426614174000")?)
+        .name(UserName::new("John Doe")?)
+        .password(UserPassword::new("password123")?)
+        .email(UserEmail::new("johndoe@example.com")?)
+        .age(UserAge::new(25)?)
+        .gender(UserGender::new("F")?)
         .parse()
         .bin()
         .unwrap();
 
     let user_bin = user_parse_bin.get();
-    let user_bin_from_bytes = user_parse_bin.from_bytes(&user_bin);
+    let user_bin_from_bytes = user_parse_bin.from_bytes(user_bin);
     assert!(user_bin_from_bytes.is_ok());
 
     let user_hex = user_parse_bin.hex();
@@ -79,4 +74,6 @@ fn parse_bin() {
     assert_eq!(user.email, "johndoe@example.com");
     assert_eq!(user.age, 25);
     assert_eq!(user.gender, "F");
+
+    Ok(())
 }
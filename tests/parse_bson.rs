@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Address {
+    pub city: String,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Customer {
+    pub name: String,
+    pub orders: u32,
+    pub tags: Vec<String>,
+    pub address: Address,
+}
+
+fn customer() -> Customer {
+    Customer {
+        name: "Ana".into(),
+        orders: 3,
+        tags: vec!["vip".into()],
+        address: Address {
+            city: "Lisbon".into(),
+        },
+    }
+}
+
+#[test]
+fn bson_document_round_trip() {
+    let bson = customer().parse().bson().unwrap();
+    let document = bson.get();
+    assert_eq!(document.get_str("name").unwrap(), "Ana");
+    assert_eq!(document.get_i64("orders").unwrap(), 3);
+    let address = document.get_document("address").unwrap();
+    assert_eq!(address.get_str("city").unwrap(), "Lisbon");
+    assert_eq!(bson.from().unwrap(), customer());
+    assert_eq!(bson.from_document(document.clone()).unwrap(), customer());
+}
+
+#[test]
+fn bson_bytes_round_trip() {
+    let bson = customer().parse().bson().unwrap();
+    let bytes = bson.bytes().unwrap();
+    // little-endian total length first, trailing NUL byte last
+    let length = u32::from_le_bytes(bytes[..4].try_into().unwrap());
+    assert_eq!(length as usize, bytes.len());
+    assert_eq!(bytes.last(), Some(&0));
+    assert_eq!(bson.from_bytes(&bytes).unwrap(), customer());
+
+    let error = bson.from_bytes(&bytes[..bytes.len() - 1]).unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Bson);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+}
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Manifest {
+    pub version: u64,
+    pub id: String,
+    pub tags: HashMap<String, u32>,
+}
+
+fn manifest(order: &[(&str, u32)]) -> Manifest {
+    Manifest {
+        version: 2,
+        id: "m".into(),
+        tags: order.iter().map(|(k, v)| (k.to_string(), *v)).collect(),
+    }
+}
+
+#[test]
+fn cbor_round_trip() {
+    let value = manifest(&[("a", 1)]);
+    let cbor = value.clone().parse().cbor().unwrap();
+    // map of three entries in declaration order, first key "version"
+    assert_eq!(&cbor.get()[..9], b"\xa3\x67version");
+    assert_eq!(cbor.from().unwrap(), value);
+    assert_eq!(cbor.from_bytes(cbor.get()).unwrap(), value);
+    assert_eq!(cbor.from_hex(&cbor.hex()).unwrap(), value);
+
+    let error = cbor.from_bytes(&cbor.get()[..4]).unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Cbor);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+}
+
+#[test]
+fn cbor_deterministic_sorts_keys() {
+    let value = manifest(&[("b", 2)]);
+    let cbor = value.clone().parse().cbor_deterministic().unwrap();
+    assert_eq!(
+        cbor.hex(),
+        // {"id": "m", "tags": {"b": 2}, "version": 2}
+        "a3626964616d6474616773a16162026776657273696f6e02"
+    );
+    assert_eq!(cbor.from().unwrap(), value);
+
+    let pairs = [("x", 1), ("yy", 2), ("z", 3), ("a", 4), ("bb", 5)];
+    let mut reversed = pairs;
+    reversed.reverse();
+    let first = manifest(&pairs).parse().cbor_deterministic().unwrap();
+    let second = manifest(&reversed).parse().cbor_deterministic().unwrap();
+    assert_eq!(first.get(), second.get());
+}
+
+#[test]
+fn cbor_field() {
+    let id = manifest(&[]).parse().field().id().cbor().unwrap();
+    assert_eq!(id.get(), b"\x61m");
+    assert_eq!(id.from().unwrap(), "m");
+}
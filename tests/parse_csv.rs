@@ -0,0 +1,136 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+use shori_core::{CsvOptions, CsvQuoting, Parsable};
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct User {
+    #[serde(rename = "userId")]
+    pub id: u32,
+    pub name: String,
+    pub active: bool,
+    #[parser(skip)]
+    #[serde(default)]
+    pub password: String,
+}
+
+fn users() -> Vec<User> {
+    vec![
+        User {
+            id: 1,
+            name: "Doe, John".into(),
+            active: true,
+            password: "secret".into(),
+        },
+        User {
+            id: 2,
+            name: "Ann".into(),
+            active: false,
+            password: "hunter2".into(),
+        },
+    ]
+}
+
+fn without_passwords() -> Vec<User> {
+    users()
+        .into_iter()
+        .map(|user| User {
+            password: String::new(),
+            ..user
+        })
+        .collect()
+}
+
+#[test]
+fn csv_round_trip() {
+    let mut rows = users().into_iter();
+    let first = rows.next().unwrap().parse().vec();
+    assert_eq!(
+        first.csv().unwrap(),
+        "userId,name,active\n1,\"Doe, John\",true\n"
+    );
+
+    let vec = shori_core::ParseVec::from(users());
+    let csv = vec.csv().unwrap();
+    assert_eq!(
+        csv,
+        "userId,name,active\n1,\"Doe, John\",true\n2,Ann,false\n"
+    );
+    let decoded = User::from_csv(csv.as_bytes()).unwrap();
+    assert_eq!(decoded.get(), without_passwords());
+}
+
+#[test]
+fn csv_delimiter_and_quoting() {
+    let options = CsvOptions::new()
+        .delimiter(b';')
+        .quoting(CsvQuoting::Always);
+    let csv = shori_core::ParseVec::from(users())
+        .csv_with(&options)
+        .unwrap();
+    assert!(csv.starts_with("\"userId\";\"name\";\"active\"\n\"1\";\"Doe, John\";"));
+    let decoded = User::from_csv_with(csv.as_bytes(), &options).unwrap();
+    assert_eq!(decoded.get(), without_passwords());
+}
+
+#[test]
+fn csv_reports_every_invalid_row() {
+    let input = "name,userId,active\nAnn,x,true\nBob,3,true\nCid,4,maybe\nDee,5\n";
+    let error = User::from_csv(input.as_bytes()).unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Csv);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+
+    let records = error.records();
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].line(), Some(2));
+    assert_eq!(records[0].key(), Some("userId"));
+    assert_eq!(records[1].line(), Some(4));
+    assert_eq!(records[1].key(), Some("active"));
+    assert_eq!(records[2].line(), Some(5));
+    assert_eq!(records[2].key(), None);
+    assert!(
+        error
+            .to_string()
+            .starts_with("failed to decode CSV: 3 invalid record(s); line 2 at `userId`: ")
+    );
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Member {
+    pub user_id: u32,
+    pub full_name: String,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Point(pub u32, pub u32);
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Draft {
+    pub title: String,
+    #[serde(skip_serializing)]
+    pub body: String,
+}
+
+#[test]
+fn csv_follows_serde_keys_and_rejects_lost_fields() {
+    let members = vec![Member {
+        user_id: 7,
+        full_name: "x".into(),
+    }];
+    let csv = shori_core::ParseVec::from(members.clone()).csv().unwrap();
+    assert_eq!(csv, "userId,fullName\n7,x\n");
+    assert_eq!(Member::from_csv(csv.as_bytes()).unwrap().get(), members);
+
+    let error = shori_core::ParseVec::from(vec![Point(1, 2)])
+        .csv()
+        .unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Csv);
+    assert_eq!(error.operation(), shori_core::Operation::Encode);
+
+    let draft = Draft {
+        title: "t".into(),
+        body: "b".into(),
+    };
+    let error = shori_core::ParseVec::from(vec![draft]).csv().unwrap_err();
+    assert_eq!(error.path(), Some("body"));
+}
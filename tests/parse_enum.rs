@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub enum OrderEvent {
+    Created,
+    Paid(u64),
+    Shipped(String, u32),
+    #[parser(rename = "cancelled")]
+    Cancelled {
+        reason: String,
+    },
+    #[parser(skip)]
+    Internal,
+}
+
+#[test]
+fn parse_enum_formats() {
+    let events = vec![
+        OrderEvent::Created,
+        OrderEvent::Paid(990),
+        OrderEvent::Shipped("DHL".into(), 2),
+        OrderEvent::Cancelled {
+            reason: "out of stock".into(),
+        },
+    ];
+
+    for event in events {
+        let json = event.clone().parse().json().unwrap();
+        assert_eq!(json.from().unwrap(), event);
+
+        let bin = event.clone().parse().bin().unwrap();
+        assert_eq!(bin.from().unwrap(), event);
+
+        let mutex = event.clone().parse().mutex().get();
+        assert_eq!(*mutex.lock().unwrap(), event);
+
+        let arc = event.clone().parse().arc().get();
+        assert_eq!(*arc, event);
+    }
+}
+
+#[test]
+fn parse_enum_variant_selector() {
+    assert_eq!(OrderEvent::Created.parse().variant().as_created(), Some(()));
+    assert_eq!(OrderEvent::Paid(990).parse().variant().as_paid(), Some(990));
+    assert_eq!(OrderEvent::Paid(990).parse().variant().as_created(), None);
+    assert_eq!(
+        OrderEvent::Shipped("DHL".into(), 2)
+            .parse()
+            .variant()
+            .as_shipped(),
+        Some(("DHL".to_string(), 2))
+    );
+    assert_eq!(
+        OrderEvent::Cancelled {
+            reason: "out of stock".into()
+        }
+        .parse()
+        .variant()
+        .as_cancelled(),
+        Some("out of stock".to_string())
+    );
+
+    let selector = OrderEvent::Paid(1).parse().variant();
+    assert!(selector.is_paid());
+    assert!(!selector.is_cancelled());
+}
+
+#[test]
+fn parse_enum_variant_names() {
+    assert_eq!(
+        OrderEventParse::VARIANTS,
+        &["Created", "Paid", "Shipped", "cancelled", "Internal"]
+    );
+    assert_eq!(OrderEvent::Created.parse().variant_name(), "Created");
+    assert_eq!(
+        OrderEvent::Cancelled {
+            reason: String::new()
+        }
+        .parse()
+        .variant_name(),
+        "cancelled"
+    );
+    assert_eq!(OrderEvent::Internal.parse().variant_name(), "Internal");
+}
@@ -0,0 +1,158 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+use shori_core::Parsable;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Database {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Level {
+    Debug,
+    Info,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Settings {
+    pub name: String,
+    pub age: u32,
+    #[serde(rename = "log")]
+    pub level: Level,
+    pub tags: Vec<String>,
+    pub timeout: Option<u64>,
+    pub db: Database,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Worker {
+    #[serde(default)]
+    pub threads: u32,
+    pub queue: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conn {
+    pub host: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pooled {
+    pub pool: u32,
+    #[serde(flatten)]
+    pub conn: Conn,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cfg {
+    pub name: String,
+    pub db: Pooled,
+}
+
+fn vars(pairs: &[(&str, &str)]) -> HashMap<String, String> {
+    pairs
+        .iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect()
+}
+
+#[test]
+fn env_fills_fields_and_nested_structs() {
+    let settings = Settings::from_env_map(
+        "APP_",
+        vars(&[
+            ("APP_NAME", "shori"),
+            ("APP_AGE", "42"),
+            ("APP_LOG", "Info"),
+            ("APP_TAGS", "web, cli"),
+            ("APP_DB__HOST", "localhost"),
+            ("app_db__port", "5432"),
+            ("OTHER_AGE", "7"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(
+        settings,
+        Settings {
+            name: "shori".into(),
+            age: 42,
+            level: Level::Info,
+            tags: vec!["web".into(), "cli".into()],
+            timeout: None,
+            db: Database {
+                host: "localhost".into(),
+                port: 5432,
+            },
+        }
+    );
+}
+
+#[test]
+fn env_reports_every_missing_and_invalid_variable() {
+    let error = Settings::from_env_map(
+        "APP_",
+        vars(&[
+            ("APP_NAME", "shori"),
+            ("APP_AGE", "old"),
+            ("APP_LOG", "Info"),
+            ("APP_TIMEOUT", "-1"),
+            ("APP_DB__PORT", "5432"),
+        ]),
+    )
+    .unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Env);
+    let mut keys: Vec<_> = error
+        .records()
+        .iter()
+        .map(|record| record.key().unwrap())
+        .collect();
+    keys.sort_unstable();
+    assert_eq!(keys, ["APP_AGE", "APP_DB__HOST", "APP_TAGS", "APP_TIMEOUT"]);
+    assert!(
+        error
+            .to_string()
+            .contains("at `APP_DB__HOST`: missing variable")
+    );
+}
+
+#[test]
+fn env_does_not_report_defaulted_fields() {
+    let error = Worker::from_env_map("W_", vars(&[("W_PORT", "http")])).unwrap_err();
+    let keys: Vec<_> = error
+        .records()
+        .iter()
+        .map(|record| record.key().unwrap())
+        .collect();
+    assert_eq!(keys, ["W_QUEUE", "W_PORT"]);
+
+    let worker =
+        Worker::from_env_map("W_", vars(&[("W_QUEUE", "jobs"), ("W_PORT", "80")])).unwrap();
+    assert_eq!(worker.threads, 0);
+}
+
+#[test]
+fn env_reports_missing_fields_of_flattened_structs() {
+    let error =
+        Cfg::from_env_map("APP_", vars(&[("APP_NAME", "x"), ("APP_DB__POOL", "3")])).unwrap_err();
+    let keys: Vec<_> = error
+        .records()
+        .iter()
+        .map(|record| record.key().unwrap())
+        .collect();
+    assert_eq!(keys, ["APP_DB__HOST"]);
+
+    let cfg = Cfg::from_env_map(
+        "APP_",
+        vars(&[
+            ("APP_NAME", "x"),
+            ("APP_DB__POOL", "3"),
+            ("APP_DB__HOST", "h"),
+        ]),
+    )
+    .unwrap();
+    assert_eq!(cfg.db.conn.host, "h");
+}
@@ -0,0 +1,123 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode)]
+pub struct View<'a> {
+    pub name: &'a str,
+}
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct Tagged<T>
+where
+    T: Clone,
+{
+    pub value: T,
+    pub tag: String,
+}
+
+fn page() -> Page<String> {
+    Page {
+        items: vec!["a".into(), "b".into()],
+        total: 2,
+    }
+}
+
+#[test]
+fn parse_generic_struct() {
+    let json: shori_core::ParseJson<Page<String>> = page().parse().json().unwrap();
+    assert_eq!(json.from().unwrap(), page());
+
+    let bin = page().parse().bin().unwrap();
+    assert_eq!(bin.from().unwrap(), page());
+
+    let toml = page().parse().toml().unwrap();
+    assert_eq!(toml.from().unwrap(), page());
+
+    let arc: std::sync::Arc<Page<String>> = page().parse().arc().get();
+    assert_eq!(*arc, page());
+
+    let map = page().parse().hashmap();
+    assert_eq!(map.get::<u64>("total"), Some(&2));
+
+    let parse = page().parse();
+    let (items, total) = parse.tuple();
+    assert_eq!(items.len(), 2);
+    assert_eq!(total, &2);
+}
+
+#[test]
+fn parse_generic_fields() {
+    let items: PageFieldItems<String> = page().parse().field().items();
+    assert_eq!(items.into_inner(), vec!["a".to_string(), "b".to_string()]);
+
+    let total: PageFieldTotal = page().parse().field().total();
+    assert_eq!(total.into_inner(), 2);
+
+    let items_mutex = page().parse().field().items().mutex();
+    assert_eq!(items_mutex.as_inner().lock().unwrap().len(), 2);
+
+    let items_json = page().parse().field().items().json().unwrap();
+    assert_eq!(*items_json.get(), serde_json::json!(["a", "b"]));
+}
+
+#[test]
+fn parse_lifetime_struct() {
+    let name = String::from("John Doe");
+    let view = View { name: &name };
+
+    let json = view.clone().parse().json().unwrap();
+    assert_eq!(json.get()["name"], "John Doe");
+
+    let bin = view.clone().parse().bin().unwrap();
+    assert!(!bin.get().is_empty());
+
+    let boxed = view.clone().parse().boxed().get();
+    assert_eq!(boxed.name, "John Doe");
+
+    let field = view.parse().field().name().vec();
+    assert_eq!(field.as_inner(), &vec!["John Doe"]);
+}
+
+#[test]
+fn parse_where_clause_struct() {
+    let tagged = Tagged {
+        value: 7u8,
+        tag: "seven".into(),
+    };
+
+    let json = tagged.clone().parse().json().unwrap();
+    assert_eq!(json.from().unwrap(), tagged);
+
+    let value = tagged.parse().field().value().ref_cell();
+    assert_eq!(*value.as_inner().borrow(), 7);
+}
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub enum Reply<T> {
+    Done(T),
+    Failed { reason: String },
+}
+
+#[test]
+fn parse_generic_enum() {
+    let reply = Reply::Done(5u32);
+
+    let bin = reply.clone().parse().bin().unwrap();
+    assert_eq!(bin.from().unwrap(), reply);
+
+    assert_eq!(reply.clone().parse().variant().as_done(), Some(5));
+    assert_eq!(reply.parse().variant_name(), "Done");
+    assert_eq!(ReplyParse::<u32>::VARIANTS, &["Done", "Failed"]);
+}
@@ -1,26 +1,25 @@
-#![cfg(all(feature = "hashmap", not(feature = "full")))]
 use kenzu::Builder;
 use shori::Parser;
 
-#[derive(Builder, PartialEq, Default, Parser, Debug, Clone)]
+#[derive(Builder, PartialEq, Parser, Debug, Clone)]
 pub struct User {
     pub id: String,
-    #[set(value = "name")]
+    #[opt(default = "name")]
     pub name: String,
     pub password: String,
-    #[build(
+    #[opt(
         pattern = r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$",
-        err = "err"
+        err = "err",
+        default = "email@example.com"
     )]
-    #[set(value = "email@example.com")]
     pub email: String,
-    #[set(value = 18)]
+    #[opt(default = 18)]
     pub age: u8,
     pub gender: String,
 }
 
 #[test]
-fn parse_hash_map() {
+fn parse_hash_map() -> Result<(), String> {
     let id = "123e4567-e89b-12d3-a456-426614174000".to_string();
     let name = "John Doe".to_string();
     let password = "password123".to_string();
@@ -29,14 +28,12 @@ fn parse_hash_map() {
     let gender = "F".to_string();
 
     let user = User::new()
-        .id(&id)
-        .name(&name)
-        .password(&password)
-        .email(&email)
-        .age(age)
-        .gender(&gender)
-        .build()
-        .unwrap()
+        .id(UserId::new(&id)?)
+        .name(UserName::new(&name)?)
+        .password(UserPassword::new(&password)?)
+        .email(UserEmail::new(&email)?)
+        .age(UserAge::new(age)?)
+        .gender(UserGender::new(&gender)?)
         .parse()
         .hashmap();
 
@@ -46,4 +43,6 @@ fn parse_hash_map() {
     assert_eq!(user.get::<String>("email").unwrap(), &email);
     assert_eq!(user.get::<u8>("age").unwrap(), &age);
     assert_eq!(user.get::<String>("gender").unwrap(), &gender);
+
+    Ok(())
 }
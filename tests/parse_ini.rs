@@ -0,0 +1,131 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+use shori_core::Parsable;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pool {
+    pub size: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Database {
+    pub host: String,
+    pub port: u16,
+    pub pool: Pool,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Vendor {
+    #[serde(rename = "product")]
+    pub name: String,
+    pub motd: String,
+    pub verbose: bool,
+    pub mirrors: Vec<String>,
+    pub retries: Option<u8>,
+    pub db: Database,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Draft {
+    pub title: String,
+    #[serde(skip_serializing)]
+    pub body: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conn {
+    pub host: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pooled {
+    pub pool: u32,
+    #[serde(flatten)]
+    pub conn: Conn,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Cfg {
+    pub name: String,
+    pub db: Pooled,
+}
+
+fn vendor() -> Vendor {
+    Vendor {
+        name: "shori".into(),
+        motd: " hello ".into(),
+        verbose: true,
+        mirrors: vec!["a.example".into(), "b.example".into()],
+        retries: None,
+        db: Database {
+            host: "localhost".into(),
+            port: 5432,
+            pool: Pool { size: 4 },
+        },
+    }
+}
+
+#[test]
+fn ini_round_trip() {
+    let ini = vendor().parse().ini().unwrap();
+    assert_eq!(
+        ini.get(),
+        "product = shori\nmotd = \" hello \"\nverbose = true\nmirrors = a.example, b.example\n\n\
+         [db]\nhost = localhost\nport = 5432\n\n[db.pool]\nsize = 4\n"
+    );
+    assert_eq!(ini.from().unwrap(), vendor());
+    assert_eq!(Vendor::from_ini(ini.get()).unwrap(), vendor());
+}
+
+#[test]
+fn ini_reports_malformed_lines_and_invalid_keys() {
+    let document = "; vendor tool\nproduct = x\nverbose = yes\nmirrors =\nnot a pair\n\
+                    [db]\nhost = h\n[db.pool]\nsize = 300\n";
+    let error = Vendor::from_ini(document).unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Ini);
+    let records: Vec<_> = error
+        .records()
+        .iter()
+        .map(|record| (record.line(), record.key()))
+        .collect();
+    assert_eq!(
+        records,
+        [
+            (Some(5), None),
+            (None, Some("motd")),
+            (None, Some("verbose")),
+            (None, Some("db.port")),
+            (None, Some("db.pool.size")),
+        ]
+    );
+
+    let mut multi_line = vendor();
+    multi_line.motd = "one\ntwo".into();
+    let error = multi_line.parse().ini().unwrap_err();
+    assert_eq!(error.path(), Some("motd"));
+}
+
+#[test]
+fn ini_fails_on_fields_serde_does_not_write() {
+    let draft = Draft {
+        title: "x".into(),
+        body: "y".into(),
+    };
+    let error = draft.parse().ini().unwrap_err();
+    assert_eq!(error.operation(), shori_core::Operation::Encode);
+    assert_eq!(error.path(), Some("body"));
+}
+
+#[test]
+fn ini_reports_missing_fields_of_flattened_structs() {
+    let error = Cfg::from_ini("name = x\n[db]\npool = 3\n").unwrap_err();
+    let keys: Vec<_> = error
+        .records()
+        .iter()
+        .map(|record| record.key().unwrap())
+        .collect();
+    assert_eq!(keys, ["db.host"]);
+
+    let cfg = Cfg::from_ini("name = x\n[db]\npool = 3\nhost = h\n").unwrap();
+    assert_eq!(cfg.db.conn.host, "h");
+}
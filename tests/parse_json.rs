@@ -1,43 +1,39 @@
-#![cfg(all(feature = "serde", feature = "serde_json", not(feature = "full")))]
-
 use kenzu::Builder;
 use serde::{Deserialize, Serialize};
 use shori::Parser;
 
-#[derive(Builder, PartialEq, Default, Parser, Debug, Clone, Serialize, Deserialize)]
+#[derive(Builder, PartialEq, Parser, Debug, Clone, Serialize, Deserialize)]
 pub struct User {
     pub id: String,
-    #[set(value = "name")]
+    #[opt(default = "name")]
     pub name: String,
     pub password: String,
-    #[build(
+    #[opt(
         pattern = r"^[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}$",
-        err = "err"
+        err = "err",
+        default = "email@example.com"
     )]
-    #[set(value = "email@example.com")]
     pub email: String,
-    #[set(value = 18)]
+    #[opt(default = 18)]
     pub age: u8,
     pub gender: String,
 }
 
 #[test]
-fn parse_json() {
+fn parse_json() -> Result<(), String> {
     let user_parse_json = User::new()
-        .id("123e4567-e89b-12d3-a456-426614174000")
-        .name("John Doe")
-        .password("password123")
-        .email("johndoe@example.com")
-        .age(25)
-        .gender("F")
-        .build()
-        .unwrap()
+        .id(UserId::new("123e4567-e89b-12d3-a456-426614174000")?)
+        .name(UserName::new("John Doe")?)
+        .password(UserPassword::new("password123")?)
+        .email(UserEmail::new("johndoe@example.com")?)
+        .age(UserAge::new(25)?)
+        .gender(UserGender::new("F")?)
         .parse()
         .json()
         .unwrap();
 
     let user_json = user_parse_json.get();
-    let from_value_user = user_parse_json.from_value(&user_json);
+    let from_value_user = user_parse_json.from_value(user_json);
     assert!(from_value_user.is_ok());
 
     let user = from_value_user.unwrap();
@@ -56,4 +52,6 @@ fn parse_json() {
     assert_eq!(user.email, "johndoe@example.com");
     assert_eq!(user.age, 25);
     assert_eq!(user.gender, "F");
+
+    Ok(())
 }
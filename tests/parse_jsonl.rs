@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+use shori_core::{Parsable, ParseVec};
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    pub id: u64,
+    pub kind: String,
+}
+
+fn events() -> Vec<Event> {
+    (1..=3)
+        .map(|id| Event {
+            id,
+            kind: format!("kind-{id}"),
+        })
+        .collect()
+}
+
+#[test]
+fn jsonl_round_trip() {
+    let mut output = Vec::new();
+    ParseVec::from(events()).jsonl(&mut output).unwrap();
+    let text = String::from_utf8(output.clone()).unwrap();
+    assert_eq!(text.lines().count(), 3);
+    assert_eq!(text.lines().next(), Some(r#"{"id":1,"kind":"kind-1"}"#));
+    assert!(text.ends_with('\n'));
+
+    let decoded: Vec<Event> = Event::jsonl_reader(output.as_slice())
+        .collect::<shori_core::Result<_>>()
+        .unwrap();
+    assert_eq!(decoded, events());
+}
+
+#[test]
+fn jsonl_errors_carry_line_numbers() {
+    let input = "{\"id\":1,\"kind\":\"a\"}\n\n{\"id\":\"x\"}\n{\"id\":4,\"kind\":\"d\"}\n";
+    let mut reader = Event::jsonl_reader(input.as_bytes());
+    assert_eq!(reader.next().unwrap().unwrap().id, 1);
+
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Json);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+    assert_eq!(error.records()[0].line(), Some(3));
+    assert!(error.to_string().contains("line 3: "));
+
+    assert_eq!(reader.next().unwrap().unwrap().id, 4);
+    assert!(reader.next().is_none());
+    assert_eq!(reader.line_number(), 4);
+}
+
+struct FailingReader;
+
+impl std::io::Read for FailingReader {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::other("connection reset"))
+    }
+}
+
+#[test]
+fn jsonl_stops_after_read_error() {
+    let mut reader = Event::jsonl_reader(FailingReader);
+    let error = reader.next().unwrap().unwrap_err();
+    assert_eq!(error.records()[0].line(), Some(1));
+    assert!(reader.next().is_none());
+    assert!(reader.next().is_none());
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Reading {
+    pub sensor: String,
+    pub value: u16,
+    pub ok: bool,
+}
+
+fn reading() -> Reading {
+    Reading {
+        sensor: "t1".into(),
+        value: 300,
+        ok: true,
+    }
+}
+
+#[test]
+fn msgpack_named_and_compact() {
+    let named = reading().parse().msgpack().unwrap();
+    // fixmap with three entries, first key "sensor"
+    assert_eq!(named.get()[0], 0x83);
+    assert_eq!(&named.get()[1..8], b"\xa6sensor");
+    assert_eq!(named.from().unwrap(), reading());
+
+    let compact = reading().parse().msgpack_compact().unwrap();
+    // fixarray with three elements, no field names
+    assert_eq!(compact.get(), b"\x93\xa2t1\xcd\x01\x2c\xc3");
+    assert_eq!(compact.from().unwrap(), reading());
+    assert_eq!(named.from_bytes(compact.get()).unwrap(), reading());
+}
+
+#[test]
+fn msgpack_hex_round_trip() {
+    let compact = reading().parse().msgpack_compact().unwrap();
+    assert_eq!(compact.hex(), "93a27431cd012cc3");
+    assert_eq!(compact.from_hex(&compact.hex()).unwrap(), reading());
+
+    let error = compact.from_hex("93a2").unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::MsgPack);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+    let error = compact.from_hex("zz").unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Hex);
+}
+
+#[test]
+fn msgpack_field() {
+    let sensor = reading().parse().field().sensor().msgpack().unwrap();
+    assert_eq!(sensor.get(), b"\xa2t1");
+    assert_eq!(sensor.from().unwrap(), "t1");
+}
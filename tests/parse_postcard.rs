@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Telemetry {
+    pub device: u16,
+    pub level: u8,
+    pub label: String,
+}
+
+fn telemetry() -> Telemetry {
+    Telemetry {
+        device: 300,
+        level: 0,
+        label: "ok".into(),
+    }
+}
+
+#[test]
+fn postcard_round_trip() {
+    let postcard = telemetry().parse().postcard().unwrap();
+    assert!(!postcard.is_cobs());
+    // varint 300, level 0, length-prefixed "ok"
+    assert_eq!(postcard.get(), b"\xac\x02\x00\x02ok");
+    assert_eq!(postcard.from().unwrap(), telemetry());
+    assert_eq!(postcard.from_hex(&postcard.hex()).unwrap(), telemetry());
+
+    let error = postcard.from_bytes(&postcard.get()[..3]).unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Postcard);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+}
+
+#[test]
+fn postcard_cobs_frames() {
+    let framed = telemetry().parse().postcard_cobs().unwrap();
+    assert!(framed.is_cobs());
+    let (delimiter, frame) = framed.get().split_last().unwrap();
+    assert_eq!(*delimiter, 0);
+    assert!(!frame.contains(&0));
+    assert_eq!(framed.from().unwrap(), telemetry());
+    assert_eq!(framed.from_bytes(framed.get()).unwrap(), telemetry());
+}
+
+#[test]
+fn postcard_into_caller_buffer() {
+    let parse = telemetry().parse();
+    let mut buffer = [0u8; 16];
+    let written = parse.postcard_into(&mut buffer).unwrap();
+    assert_eq!(&buffer[..written], b"\xac\x02\x00\x02ok");
+
+    let mut frame = [0u8; 16];
+    let framed = parse.postcard_cobs_into(&mut frame).unwrap();
+    assert_eq!(frame[framed - 1], 0);
+
+    let mut small = [0u8; 2];
+    let error = parse.postcard_into(&mut small).unwrap_err();
+    assert_eq!(error.operation(), shori_core::Operation::Encode);
+
+    let label = telemetry().parse().field().label().postcard().unwrap();
+    assert_eq!(label.get(), b"\x02ok");
+}
@@ -0,0 +1,100 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+use shori_core::Parsable;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Paging {
+    pub page: u32,
+    pub size: Option<u8>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Order {
+    Asc,
+    Desc,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Search {
+    #[serde(rename = "q")]
+    pub query: String,
+    pub tags: Vec<String>,
+    pub paging: Paging,
+    pub order: Order,
+    pub exact: bool,
+    pub limit: Option<u64>,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Filter {
+    pub min_price: u32,
+    pub brand_names: Vec<String>,
+}
+
+fn search() -> Search {
+    Search {
+        query: "rust & serde".into(),
+        tags: vec!["web".into(), "cli".into()],
+        paging: Paging {
+            page: 2,
+            size: Some(50),
+        },
+        order: Order::Desc,
+        exact: false,
+        limit: None,
+    }
+}
+
+#[test]
+fn query_string_round_trip() {
+    let query = search().parse().query_string().unwrap();
+    assert_eq!(
+        query.get(),
+        "q=rust+%26+serde&tags=web&tags=cli&paging[page]=2&paging[size]=50&order=Desc&exact=false"
+    );
+    assert_eq!(query.from().unwrap(), search());
+    assert_eq!(Search::from_query_string(query.get()).unwrap(), search());
+}
+
+#[test]
+fn query_string_coerces_field_types() {
+    let decoded =
+        Search::from_query_string("?exact=true&order=Asc&paging%5Bpage%5D=7&tags=one&q=x&limit=9")
+            .unwrap();
+    assert_eq!(decoded.tags, vec!["one"]);
+    assert_eq!(
+        decoded.paging,
+        Paging {
+            page: 7,
+            size: None
+        }
+    );
+    assert_eq!(decoded.limit, Some(9));
+    assert!(decoded.exact);
+
+    let error =
+        Search::from_query_string("q=x&tags=a&paging[page]=many&order=Asc&exact=true").unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::QueryString);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+
+    let error = Search::from_query_string("q=x&q[nested]=y").unwrap_err();
+    assert_eq!(error.path(), Some("q[nested]"));
+}
+
+#[test]
+fn query_string_follows_serde_keys_and_keeps_empty_sequences() {
+    let filter = Filter {
+        min_price: 10,
+        brand_names: vec!["acme".into()],
+    };
+    let query = filter.clone().parse().query_string().unwrap();
+    assert_eq!(query.get(), "minPrice=10&brandNames=acme");
+    assert_eq!(query.from().unwrap(), filter);
+
+    let mut untagged = search();
+    untagged.tags.clear();
+    let query = untagged.clone().parse().query_string().unwrap();
+    assert!(query.get().contains("&tags=&"));
+    assert_eq!(Search::from_query_string(query.get()).unwrap(), untagged);
+}
@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Drop {
+    Nothing,
+    Gold(u32),
+    Item { name: String, rarity: u8 },
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Monster {
+    pub name: String,
+    pub spawn: (i32, i32),
+    pub drops: Vec<Drop>,
+}
+
+fn monster() -> Monster {
+    Monster {
+        name: "slime".into(),
+        spawn: (-4, 2),
+        drops: vec![
+            Drop::Nothing,
+            Drop::Gold(5),
+            Drop::Item {
+                name: "gel".into(),
+                rarity: 1,
+            },
+        ],
+    }
+}
+
+#[test]
+fn ron_compact_round_trip() {
+    let ron = monster().parse().ron().unwrap();
+    assert_eq!(
+        ron.get(),
+        r#"(name:"slime",spawn:(-4,2),drops:[Nothing,Gold(5),Item(name:"gel",rarity:1)])"#
+    );
+    assert_eq!(ron.from().unwrap(), monster());
+}
+
+#[test]
+fn ron_pretty_round_trip() {
+    let ron = monster().parse().ron_pretty().unwrap();
+    assert!(ron.get().lines().count() > 1);
+    assert!(ron.get().contains("    name: \"slime\","));
+    assert_eq!(ron.from_str(ron.get()).unwrap(), monster());
+    assert_eq!(ron.from().unwrap(), monster());
+}
+
+#[test]
+fn ron_enum_and_errors() {
+    let ron = Drop::Gold(9).parse().ron().unwrap();
+    assert_eq!(ron.get(), "Gold(9)");
+
+    let error = ron.from_str("Silver(1)").unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Ron);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+    assert_eq!(ron.from().unwrap(), Drop::Gold(9));
+
+    let spawn = monster().parse().field().spawn().ron().unwrap();
+    assert_eq!(spawn.string(), "(-4,2)");
+}
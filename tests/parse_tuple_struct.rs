@@ -0,0 +1,83 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct UserId(String);
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct Point(i32, i32);
+
+#[derive(
+    Parser, Debug, Clone, PartialEq, Serialize, Deserialize, bincode::Encode, bincode::Decode,
+)]
+pub struct Marker;
+
+#[test]
+fn parse_newtype() {
+    let id = UserId("123e4567-e89b-12d3-a456-426614174000".into());
+
+    let parse = id.clone().parse();
+    let (inner,) = parse.tuple();
+    assert_eq!(inner, "123e4567-e89b-12d3-a456-426614174000");
+
+    let json = id.clone().parse().json().unwrap();
+    assert_eq!(json.get(), "123e4567-e89b-12d3-a456-426614174000");
+    assert_eq!(json.from().unwrap(), id);
+
+    let bin = id.clone().parse().bin().unwrap();
+    assert_eq!(bin.from().unwrap(), id);
+
+    let field = id.clone().parse().field()._0();
+    assert_eq!(field.into_inner(), "123e4567-e89b-12d3-a456-426614174000");
+
+    let argon = id.parse().field()._0().argon2_hash().unwrap();
+    assert!(
+        argon
+            .verify("123e4567-e89b-12d3-a456-426614174000")
+            .unwrap()
+    );
+}
+
+#[test]
+fn parse_tuple_struct() {
+    let point = Point(3, -4);
+
+    assert_eq!(point.clone().parse().tuple(), (&3, &-4));
+
+    let map = point.clone().parse().hashmap();
+    assert_eq!(map.get::<i32>("0"), Some(&3));
+    assert_eq!(map.get::<i32>("1"), Some(&-4));
+
+    let y = point.clone().parse().field()._1().mutex();
+    assert_eq!(*y.as_inner().lock().unwrap(), -4);
+
+    let toml_err = point.clone().parse().toml();
+    assert!(toml_err.is_err());
+
+    let json = point.clone().parse().json().unwrap();
+    assert_eq!(json.from().unwrap(), point);
+
+    let vec = point.clone().parse().vec().get();
+    assert_eq!(vec, vec![point]);
+}
+
+#[test]
+fn parse_unit_struct() {
+    let marker = Marker;
+
+    assert_eq!(marker.clone().parse().tuple(), ());
+    assert!(marker.clone().parse().hashmap().get::<()>("0").is_none());
+
+    let json = marker.clone().parse().json().unwrap();
+    assert_eq!(json.from().unwrap(), Marker);
+
+    let bin = marker.clone().parse().bin().unwrap();
+    assert_eq!(bin.from().unwrap(), Marker);
+
+    let arc = marker.parse().arc().get();
+    assert_eq!(*arc, Marker);
+}
@@ -0,0 +1,124 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+use shori_core::Parsable;
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Order {
+    #[parser(xml_attribute)]
+    pub id: u32,
+    #[parser(xml_attribute)]
+    #[serde(rename = "currency")]
+    pub code: String,
+    #[serde(rename = "customerName")]
+    pub customer: String,
+    pub items: Vec<String>,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Shipment {
+    #[parser(xml_attribute, rename = "ref")]
+    pub tracking_id: String,
+    pub due_date: String,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tagged {
+    #[parser(xml_attribute)]
+    pub id: Option<u32>,
+    #[parser(xml_attribute)]
+    pub label: Option<String>,
+    pub name: String,
+}
+
+fn order() -> Order {
+    Order {
+        id: 7,
+        code: "EUR".into(),
+        customer: "Ann & Co".into(),
+        items: vec!["pen".into(), "ink".into()],
+    }
+}
+
+#[test]
+fn xml_attributes_and_elements() {
+    let xml = order().parse().xml().unwrap();
+    assert_eq!(
+        xml.get(),
+        "<Order id=\"7\" currency=\"EUR\"><customerName>Ann &amp; Co</customerName>\
+         <items>pen</items><items>ink</items></Order>"
+    );
+    assert_eq!(xml.from().unwrap(), order());
+    assert_eq!(Order::from_xml(xml.get()).unwrap(), order());
+}
+
+#[test]
+fn xml_custom_root_and_errors() {
+    let xml = order().parse().xml_with_root("purchase").unwrap();
+    assert!(
+        xml.get()
+            .starts_with("<purchase id=\"7\" currency=\"EUR\">")
+    );
+    assert_eq!(xml.from().unwrap(), order());
+
+    let empty = Order::from_xml("<Order id=\"1\" currency=\"USD\"/>").unwrap_err();
+    assert_eq!(empty.format(), shori_core::Format::Xml);
+    assert_eq!(empty.operation(), shori_core::Operation::Decode);
+
+    let error =
+        Order::from_xml("<Order id=\"x\" currency=\"USD\"><customerName>A</customerName></Order>")
+            .unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Xml);
+}
+
+#[test]
+fn xml_field() {
+    let customer = order().parse().field().customer().xml().unwrap();
+    assert_eq!(customer.get(), "<customerName>Ann &amp; Co</customerName>");
+    assert_eq!(customer.from().unwrap(), "Ann & Co");
+}
+
+#[test]
+fn xml_follows_serde_rename_all() {
+    let shipment = Shipment {
+        tracking_id: "T1".into(),
+        due_date: "2024-01-02".into(),
+    };
+    let xml = shipment.clone().parse().xml().unwrap();
+    assert_eq!(
+        xml.get(),
+        "<Shipment trackingId=\"T1\"><dueDate>2024-01-02</dueDate></Shipment>"
+    );
+    assert_eq!(Shipment::from_xml(xml.get()).unwrap(), shipment);
+
+    let due_date = shipment.parse().field().due_date().xml().unwrap();
+    assert_eq!(due_date.get(), "<dueDate>2024-01-02</dueDate>");
+}
+
+#[test]
+fn xml_empty_attributes_round_trip() {
+    let untagged = Tagged {
+        id: None,
+        label: None,
+        name: "n".into(),
+    };
+    let xml = untagged.clone().parse().xml().unwrap();
+    assert_eq!(xml.get(), "<Tagged><name>n</name></Tagged>");
+    assert_eq!(Tagged::from_xml(xml.get()).unwrap(), untagged);
+
+    let tagged = Tagged {
+        id: Some(4),
+        label: Some("l".into()),
+        name: "n".into(),
+    };
+    let xml = tagged.clone().parse().xml().unwrap();
+    assert_eq!(
+        xml.get(),
+        "<Tagged id=\"4\" label=\"l\"><name>n</name></Tagged>"
+    );
+    assert_eq!(xml.from().unwrap(), tagged);
+    assert_eq!(
+        Tagged::from_xml("<Tagged id=\"\" label=\"\"><name>n</name></Tagged>").unwrap(),
+        untagged
+    );
+}
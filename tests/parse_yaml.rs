@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Deployment {
+    pub name: String,
+    pub replicas: u32,
+    pub labels: Vec<String>,
+}
+
+fn deployment() -> Deployment {
+    Deployment {
+        name: "api".into(),
+        replicas: 3,
+        labels: vec!["web".into(), "prod".into()],
+    }
+}
+
+#[test]
+fn yaml_round_trip() {
+    let yaml = deployment().parse().yaml().unwrap();
+    assert_eq!(yaml.get()["replicas"], serde_yaml::Value::from(3));
+
+    let document = yaml.string().unwrap();
+    assert!(document.contains("name: api"));
+    assert_eq!(yaml.from_str(&document).unwrap(), deployment());
+    assert_eq!(yaml.from_value(yaml.get()).unwrap(), deployment());
+    assert_eq!(yaml.from().unwrap(), deployment());
+}
+
+#[test]
+fn yaml_decode_error() {
+    let yaml = deployment().parse().yaml().unwrap();
+    let error = yaml.from_str("name: [unclosed").unwrap_err();
+    assert_eq!(error.format(), shori_core::Format::Yaml);
+    assert_eq!(error.operation(), shori_core::Operation::Decode);
+}
+
+#[test]
+fn yaml_adapters_and_field() {
+    let arc = deployment().parse().yaml().unwrap().arc();
+    assert_eq!(arc["name"], serde_yaml::Value::from("api"));
+    let mutex = deployment().parse().yaml().unwrap().mutex();
+    assert_eq!(
+        mutex.lock().unwrap()["replicas"],
+        serde_yaml::Value::from(3)
+    );
+    let cell = deployment().parse().yaml().unwrap().ref_cell();
+    assert!(cell.borrow().is_mapping());
+
+    let labels = deployment().parse().field().labels().yaml().unwrap();
+    assert_eq!(labels.string().unwrap(), "- web\n- prod\n");
+    assert_eq!(labels.from().unwrap(), vec!["web", "prod"]);
+}
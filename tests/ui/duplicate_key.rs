@@ -0,0 +1,17 @@
+use shori::Parser;
+
+#[derive(Parser)]
+struct User {
+    #[parser(rename = "name")]
+    id: u32,
+    name: String,
+}
+
+#[derive(Parser)]
+enum Event {
+    Created,
+    #[parser(rename = "Created")]
+    Renamed,
+}
+
+fn main() {}
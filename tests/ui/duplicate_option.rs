@@ -0,0 +1,11 @@
+use shori::Parser;
+
+#[derive(Parser)]
+#[parser(name = "Account")]
+#[parser(name = "Profile")]
+struct User {
+    #[parser(skip, skip)]
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use shori::Parser;
+
+#[derive(Parser, Debug)]
+#[parser(exclude(unsafe_cell))]
+struct User {
+    id: u32,
+}
+
+#[derive(Parser, Debug)]
+#[parser(only(json))]
+struct Order {
+    id: u32,
+}
+
+fn main() {
+    let _ = User { id: 1 }.parse().unsafe_cell();
+    let _ = Order { id: 1 }.parse().field().id().arc();
+}
@@ -0,0 +1,13 @@
+use shori::Parser;
+
+#[derive(Parser, Debug)]
+pub struct Local {
+    pub shared: std::rc::Rc<String>,
+}
+
+fn main() {
+    let local = Local {
+        shared: std::rc::Rc::new(String::new()),
+    };
+    let _ = local.parse().hashmap();
+}
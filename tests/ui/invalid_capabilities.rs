@@ -0,0 +1,9 @@
+use shori::Parser;
+
+#[derive(Parser)]
+#[parser(only(json, protobuf, json), exclude(arc))]
+struct User {
+    id: u32,
+}
+
+fn main() {}
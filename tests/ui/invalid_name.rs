@@ -0,0 +1,10 @@
+use shori::Parser;
+
+#[derive(Parser)]
+#[parser(name = "not an ident")]
+struct User {
+    #[parser(rename = "")]
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use shori::Parser;
+
+#[derive(Parser)]
+#[parser(vis = "crate only")]
+struct User {
+    id: u32,
+}
+
+fn main() {}
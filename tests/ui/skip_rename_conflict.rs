@@ -0,0 +1,9 @@
+use shori::Parser;
+
+#[derive(Parser)]
+struct User {
+    #[parser(skip, rename = "userId")]
+    id: u32,
+}
+
+fn main() {}
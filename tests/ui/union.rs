@@ -0,0 +1,9 @@
+use shori::Parser;
+
+#[derive(Parser)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
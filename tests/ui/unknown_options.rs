@@ -0,0 +1,12 @@
+use shori::Parser;
+
+#[derive(Parser)]
+#[parser(prefix = "Account", name = "Account", formats(json))]
+struct User {
+    #[parser(skip, alias = "user_id")]
+    id: u32,
+    #[parser(hidden)]
+    name: String,
+}
+
+fn main() {}
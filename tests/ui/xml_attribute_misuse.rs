@@ -0,0 +1,15 @@
+use shori::Parser;
+
+#[derive(Parser)]
+struct User {
+    #[parser(skip, xml_attribute)]
+    id: u32,
+}
+
+#[derive(Parser)]
+enum Status {
+    #[parser(xml_attribute)]
+    Active,
+}
+
+fn main() {}
@@ -0,0 +1,54 @@
+#![deny(private_interfaces, private_bounds, unreachable_pub)]
+
+use serde::{Deserialize, Serialize};
+use shori::Parser;
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct Draft {
+    pub(crate) title: String,
+}
+
+#[derive(Parser, Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) enum Stage {
+    Open,
+    Closed(String),
+}
+
+mod models {
+    use shori::Parser;
+
+    #[derive(Parser, Debug, Clone, PartialEq)]
+    #[parser(vis = "pub(crate)")]
+    struct Secret {
+        token: String,
+    }
+
+    pub(crate) fn secret_token() -> String {
+        let secret = Secret {
+            token: "abc".into(),
+        };
+        secret.parse().field().token().into_inner()
+    }
+}
+
+#[test]
+fn generated_items_follow_the_type_visibility() {
+    let draft = Draft {
+        title: "Notes".into(),
+    };
+    let parse: DraftParse = draft.clone().parse();
+    let title: DraftFieldTitle = parse.field().title();
+    assert_eq!(title.get(), "Notes");
+
+    let selector: DraftFields = draft.clone().parse().field();
+    assert_eq!(selector.into_parse().into_inner(), draft);
+
+    let stage: StageVariants = Stage::Closed("done".into()).parse().variant();
+    assert_eq!(stage.as_closed().as_deref(), Some("done"));
+}
+
+#[test]
+fn vis_override() {
+    let _: Option<models::SecretFieldToken> = None;
+    assert_eq!(models::secret_token(), "abc");
+}